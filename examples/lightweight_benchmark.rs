@@ -0,0 +1,77 @@
+//! Compares the time taken to read frames with
+//! regular and lightweight segment data.
+//!
+//! Reads are timed end to end from a live Vicon
+//! data stream, as lightweight segments change
+//! what the SDK receives, which can't be
+//! simulated without hardware.
+//!
+//! Usage: `lightweight_benchmark <hostname> [frames]`
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use vicon_sys::{sys::ViconSystem, HasViconHardware, OutputRotation, StreamMode};
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let hostname = args
+        .next()
+        .expect("usage: lightweight_benchmark <hostname> [frames]");
+    let frames: u32 = args.next().map_or(1000, |frames| frames.parse().unwrap());
+
+    benchmark(&hostname, frames);
+}
+
+/// Times reads of `frames` frames from the Vicon
+/// data stream at `hostname`.
+fn benchmark(hostname: &str, frames: u32) {
+    // Every frame is pushed, so reads time
+    // the data stream rather than polling.
+    let mut vicon = ViconSystem::builder(hostname)
+        .stream_mode(StreamMode::ServerPush)
+        .build()
+        .unwrap();
+
+    discard_buffered_frames(&mut vicon);
+    let regular = time(frames, || {
+        vicon.read_frame(OutputRotation::Quaternion).unwrap()
+    });
+
+    vicon.enable_lightweight_segments().unwrap();
+    discard_buffered_frames(&mut vicon);
+    let lightweight = time(frames, || {
+        vicon.read_frame(OutputRotation::Quaternion).unwrap()
+    });
+
+    println!("regular:     {:?} per frame", regular);
+    println!("lightweight: {:?} per frame", lightweight);
+}
+
+/// Discards the frames pushed before the data stream's
+/// latest configuration took effect, by reading until
+/// a read blocks waiting for a new frame.
+fn discard_buffered_frames(vicon: &mut ViconSystem) {
+    loop {
+        let start = Instant::now();
+        let frame = vicon.read_frame(OutputRotation::Quaternion).unwrap();
+
+        // Without a frame rate, buffered frames can't
+        // be told apart from new ones; assume none.
+        if frame.frame_rate <= 0.0 || start.elapsed().as_secs_f64() >= 0.5 / frame.frame_rate {
+            break;
+        }
+    }
+}
+
+/// Returns the mean time taken by each of
+/// `frames` invocations of `read`, or zero
+/// if `frames` is zero.
+fn time<T>(frames: u32, mut read: impl FnMut() -> T) -> Duration {
+    let start = Instant::now();
+    for _ in 0..frames {
+        black_box(read());
+    }
+    start.elapsed().checked_div(frames).unwrap_or_default()
+}
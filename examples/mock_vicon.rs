@@ -1,65 +1,415 @@
+use std::sync::Arc;
+
 use vicon_sys::HasViconHardware;
+use vicon_sys::SegmentTree;
+use vicon_sys::StreamMode;
+use vicon_sys::ViconFrame;
+use vicon_sys::ViconMarker;
+use vicon_sys::ViconMarkerFrame;
+use vicon_sys::ViconPointMarker;
+use vicon_sys::ViconSegment;
 use vicon_sys::ViconSubject;
 
+/// Rate (in Hz) at which the mock captures frames.
+const FRAME_RATE: f64 = 100.0;
+
 pub struct MockVicon {
     pub subjects: Vec<ViconSubject>,
+    pub unlabeled_markers: Vec<ViconPointMarker>,
+    pub frame_number: u32,
+    pub subject_filter: Vec<String>,
+
+    /// Mode in which frames are streamed, which
+    /// determines the frame returned by each read.
+    pub stream_mode: StreamMode,
+
+    /// Number of the latest frame captured by the mock,
+    /// which only advances via [`MockVicon::capture`]
+    /// (or reads waiting for a frame).
+    pub captured: u32,
+
+    /// Frame requested by the previous read
+    /// in [`StreamMode::ClientPullPreFetch`].
+    prefetched: Option<u32>,
 }
 
 impl MockVicon {
     pub fn new() -> Self {
+        let segments = vec![mock_segment(
+            "mob_6",
+            None,
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0],
+        )];
+        let hierarchy = Arc::new(SegmentTree::from_parents(&segments));
+        let markers = vec![
+            mock_marker("mob_61", "mob_6", [0.1, 0.0, 0.0]),
+            mock_marker("mob_62", "mob_6", [0.0, 0.1, 0.0]),
+            mock_marker("mob_63", "mob_6", [-0.1, 0.0, 0.0]),
+            mock_marker("mob_64", "mob_6", [0.0, -0.1, 0.02]),
+        ];
+
+        // A synthetic three-link arm.
+        let arm_segments = vec![
+            mock_segment("base", None, [1.0, 0.0, 0.0], [1.0, 0.0, 0.0]),
+            mock_segment("upper_arm", Some("base"), [1.0, 0.0, 0.2], [0.0, 0.0, 0.2]),
+            mock_segment(
+                "forearm",
+                Some("upper_arm"),
+                [1.0, 0.0, 0.5],
+                [0.0, 0.0, 0.3],
+            ),
+        ];
+        let arm_hierarchy = Arc::new(SegmentTree::from_parents(&arm_segments));
+
         MockVicon {
-            subjects: vec![ViconSubject {
-                name: "mob_6".to_string(),
-                origin: nalgebra::Vector3::new(0.0, 0.0, 0.0),
-                rotation: vicon_sys::RotationType::Quaternion(nalgebra::UnitQuaternion::identity()),
-                occluded: false,
+            subjects: vec![
+                ViconSubject {
+                    name: "mob_6".to_string(),
+                    origin: nalgebra::Vector3::new(0.0, 0.0, 0.0),
+                    rotation: vicon_sys::RotationType::Quaternion(
+                        nalgebra::UnitQuaternion::identity(),
+                    ),
+                    occluded: false,
+                    quality: Some(0.95),
+                    low_quality: false,
+                    segments,
+                    hierarchy,
+                    markers,
+                },
+                ViconSubject {
+                    name: "arm".to_string(),
+                    origin: nalgebra::Vector3::new(1.0, 0.0, 0.0),
+                    rotation: vicon_sys::RotationType::Quaternion(
+                        nalgebra::UnitQuaternion::identity(),
+                    ),
+                    occluded: false,
+                    quality: None,
+                    low_quality: false,
+                    segments: arm_segments,
+                    hierarchy: arm_hierarchy,
+                    markers: Vec::new(),
+                },
+            ],
+            // A loose reflective ball.
+            unlabeled_markers: vec![ViconPointMarker {
+                position: nalgebra::Vector3::new(2.0, 1.0, 0.05),
+                trajectory_id: 1,
             }],
+            frame_number: 0,
+            subject_filter: Vec::new(),
+            stream_mode: StreamMode::default(),
+            captured: 1,
+            prefetched: None,
+        }
+    }
+
+    /// Simulates the passage of time while
+    /// the mock captures `frames` frames.
+    pub fn capture(&mut self, frames: u32) {
+        self.captured += frames;
+    }
+
+    /// Returns the number of the frame returned by the
+    /// next read, following the mock's stream mode.
+    ///
+    /// Reads "wait" for frames which have not been
+    /// captured yet by capturing them immediately.
+    fn next_frame_number(&mut self) -> u32 {
+        let number = match self.stream_mode {
+            // Reads return the latest frame.
+            StreamMode::ClientPull => self.captured,
+
+            // Reads return the frame requested by the
+            // previous read (or the latest frame, for
+            // the first read), and request the frame
+            // after the latest for the next read.
+            StreamMode::ClientPullPreFetch => self.prefetched.unwrap_or(self.captured),
+
+            // Reads return every frame in turn.
+            StreamMode::ServerPush => self.frame_number + 1,
+        };
+
+        self.captured = self.captured.max(number);
+        if self.stream_mode == StreamMode::ClientPullPreFetch {
+            self.prefetched = Some(self.captured + 1);
         }
+
+        self.frame_number = number;
+        number
+    }
+
+    /// Returns the subjects which pass the subject filter.
+    fn filtered_subjects(&self) -> impl Iterator<Item = &ViconSubject> {
+        self.subjects
+            .iter()
+            .filter(|subject| self.passes_filter(subject))
+    }
+
+    /// Returns `true` iff `subject` passes the subject filter.
+    fn passes_filter(&self, subject: &ViconSubject) -> bool {
+        self.subject_filter.is_empty() || self.subject_filter.contains(&subject.name)
+    }
+}
+
+/// Returns an unoccluded, unrotated segment named `name`
+/// positioned at `origin`, and at `local_origin`
+/// relative to its `parent`.
+fn mock_segment(
+    name: &str,
+    parent: Option<&str>,
+    origin: [f64; 3],
+    local_origin: [f64; 3],
+) -> ViconSegment {
+    ViconSegment {
+        name: name.to_string(),
+        parent: parent.map(str::to_string),
+        origin: nalgebra::Vector3::from(origin),
+        rotation: vicon_sys::RotationType::Quaternion(nalgebra::UnitQuaternion::identity()),
+        occluded: false,
+        local_origin: Some(nalgebra::Vector3::from(local_origin)),
+        local_rotation: Some(vicon_sys::RotationType::Quaternion(
+            nalgebra::UnitQuaternion::identity(),
+        )),
+    }
+}
+
+/// Returns an unoccluded marker named `name`
+/// attached to `parent` and positioned at `position`.
+fn mock_marker(name: &str, parent: &str, position: [f64; 3]) -> ViconMarker {
+    ViconMarker {
+        name: name.to_string(),
+        parent: Some(parent.to_string()),
+        position: nalgebra::Vector3::from(position),
+        occluded: false,
+    }
+}
+
+impl Default for MockVicon {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 impl HasViconHardware for MockVicon {
-    fn read_frame_subjects(
+    fn read_frame(
         &mut self,
         rotation_type: vicon_sys::OutputRotation,
-    ) -> Result<Vec<ViconSubject>, vicon_sys::ViconError> {
-        Ok(
-            self.subjects
-                .clone()
-                .into_iter()
-                .map(|mut s| {
-                    s.rotation = match s.rotation {
-                        vicon_sys::RotationType::Euler(euler) => match rotation_type {
-                            vicon_sys::OutputRotation::Euler => {
-                                vicon_sys::RotationType::Euler(euler)
-                            }
-                            vicon_sys::OutputRotation::Quaternion => {
-                                vicon_sys::RotationType::Quaternion(
-                                    nalgebra::UnitQuaternion::identity(),
-                                )
-                            }
-                        },
-                        vicon_sys::RotationType::Quaternion(quat) => match rotation_type {
-                            vicon_sys::OutputRotation::Euler => {
-                                let euler = quat.euler_angles();
-                                vicon_sys::RotationType::Euler(nalgebra::Vector3::new(
-                                    euler.0, euler.1, euler.2,
-                                ))
-                            }
-                            vicon_sys::OutputRotation::Quaternion => {
-                                vicon_sys::RotationType::Quaternion(quat)
-                            }
-                        },
-                    };
-                    s
-                })
-                .collect(),
-        )
+    ) -> Result<ViconFrame, vicon_sys::ViconError> {
+        let number = self.next_frame_number();
+
+        let subjects = self
+            .filtered_subjects()
+            .cloned()
+            .map(|mut s| {
+                s.rotation = s.rotation.to_output(rotation_type);
+                s.segments = s
+                    .segments
+                    .into_iter()
+                    .map(|mut segment| {
+                        segment.rotation = segment.rotation.to_output(rotation_type);
+                        segment.local_rotation = segment
+                            .local_rotation
+                            .map(|rotation| rotation.to_output(rotation_type));
+                        segment
+                    })
+                    .collect();
+                s
+            })
+            .collect();
+
+        Ok(ViconFrame {
+            hardware_number: Some(number),
+            subjects,
+            ..ViconFrame::new(number, FRAME_RATE)
+        })
+    }
+
+    fn read_frame_unlabeled_markers(
+        &mut self,
+    ) -> Result<Vec<ViconPointMarker>, vicon_sys::ViconError> {
+        Ok(self.unlabeled_markers.clone())
+    }
+
+    fn read_frame_labeled_markers(&mut self) -> Result<ViconMarkerFrame, vicon_sys::ViconError> {
+        let number = self.next_frame_number();
+
+        // Each marker's trajectory ID is its index among
+        // all subjects' markers, so it stays the same
+        // whichever markers are occluded or filtered.
+        let markers = self
+            .subjects
+            .iter()
+            .flat_map(|subject| subject.markers.iter().map(move |marker| (subject, marker)))
+            .enumerate()
+            .filter(|(_, (subject, marker))| !marker.occluded && self.passes_filter(subject))
+            .map(|(i, (_, marker))| ViconPointMarker {
+                position: marker.position,
+                trajectory_id: i as u32,
+            })
+            .collect();
+
+        Ok(ViconMarkerFrame {
+            number,
+            received_at: std::time::SystemTime::now(),
+            markers,
+        })
+    }
+
+    fn set_subject_filter(&mut self, subject_names: &[&str]) -> Result<(), vicon_sys::ViconError> {
+        // Like the Vicon SDK, reject names which
+        // can't be passed as C strings.
+        if subject_names.iter().any(|name| name.contains('\0')) {
+            return Err(vicon_sys::ViconError::SdkError {
+                source: vicon_sys::ViconSdkStatus::InvalidSubjectName,
+            });
+        }
+
+        self.subject_filter = subject_names.iter().map(|name| name.to_string()).collect();
+        Ok(())
+    }
+
+    fn clear_subject_filter(&mut self) -> Result<(), vicon_sys::ViconError> {
+        self.subject_filter.clear();
+        Ok(())
     }
 }
 
 fn main() {
     let mut mock_vicon = MockVicon::new();
-    let subjects = mock_vicon.read_frame_subjects(vicon_sys::OutputRotation::Quaternion);
+    let subjects = mock_vicon
+        .read_frame_subjects(vicon_sys::OutputRotation::Quaternion)
+        .unwrap();
     println!("{:?}", subjects);
+
+    // Walk the arm's joint chain from its root.
+    let arm = subjects.iter().find(|s| s.name == "arm").unwrap();
+    let chain: Vec<&str> = arm
+        .segment_chain("forearm")
+        .iter()
+        .map(|segment| segment.name.as_str())
+        .collect();
+    println!("arm chain: {}", chain.join(" -> "));
+
+    let labeled_markers = mock_vicon.read_frame_labeled_markers().unwrap();
+    println!(
+        "frame {}: {:?}",
+        labeled_markers.number, labeled_markers.markers
+    );
+
+    let frame = mock_vicon
+        .read_frame(vicon_sys::OutputRotation::Euler)
+        .unwrap();
+    println!(
+        "frame {} at {} Hz ({} subjects)",
+        frame.number,
+        frame.frame_rate,
+        frame.subjects.len()
+    );
+
+    // Unlabeled markers are read from the same frame.
+    let unlabeled_markers = mock_vicon.read_frame_unlabeled_markers().unwrap();
+    println!("{:?}", unlabeled_markers);
+
+    // Only stream the arm.
+    mock_vicon.set_subject_filter(&["arm"]).unwrap();
+    let subjects = mock_vicon
+        .read_frame_subjects(vicon_sys::OutputRotation::Quaternion)
+        .unwrap();
+    assert_eq!(subjects.len(), 1);
+    println!("filtered to: {}", subjects[0].name);
+    mock_vicon.clear_subject_filter().unwrap();
+
+    // Read frames on a background thread.
+    let stream = vicon_sys::ViconStream::new(mock_vicon, vicon_sys::OutputRotation::Quaternion, 8);
+    let frame = stream.frames().recv().unwrap().unwrap();
+    println!("streamed frame {}", frame.number);
+}
+
+#[cfg(test)]
+mod tests {
+    use vicon_sys::OutputRotation;
+
+    use super::*;
+
+    /// Reads the next frame from `vicon`.
+    fn read(vicon: &mut MockVicon) -> ViconFrame {
+        vicon.read_frame(OutputRotation::Quaternion).unwrap()
+    }
+
+    #[test]
+    fn subject_filter_rejects_invalid_names() {
+        let mut vicon = MockVicon::new();
+        vicon.set_subject_filter(&["arm"]).unwrap();
+
+        // Invalid names leave the filter unchanged.
+        assert!(vicon.set_subject_filter(&["base", "arm\0"]).is_err());
+        assert_eq!(vicon.subject_filter, ["arm"]);
+        let subjects = vicon
+            .read_frame_subjects(OutputRotation::Quaternion)
+            .unwrap();
+        assert_eq!(subjects.len(), 1);
+    }
+
+    #[test]
+    fn client_pull_reads_latest_frame() {
+        let mut vicon = MockVicon::new();
+        let first = read(&mut vicon);
+
+        // Frames captured between reads are skipped.
+        vicon.capture(5);
+        let second = read(&mut vicon);
+        assert_eq!(second.number, vicon.captured);
+        assert_eq!(second.frames_since(&first), 5);
+
+        // Reads without new frames repeat the latest.
+        let third = read(&mut vicon);
+        assert_eq!(third.frames_since(&second), 0);
+    }
+
+    #[test]
+    fn pre_fetch_reads_frame_requested_by_previous_read() {
+        let mut vicon = MockVicon {
+            stream_mode: StreamMode::ClientPullPreFetch,
+            ..MockVicon::new()
+        };
+        let first = read(&mut vicon);
+        assert_eq!(first.number, vicon.captured);
+
+        // The next read returns the frame requested by
+        // the first, which is behind the latest frame.
+        vicon.capture(5);
+        let second = read(&mut vicon);
+        assert_eq!(second.frames_since(&first), 1);
+        assert!(second.number < vicon.captured);
+
+        // Reads wait for requested frames to arrive.
+        let third = read(&mut vicon);
+        assert_eq!(third.number, vicon.captured);
+        let fourth = read(&mut vicon);
+        assert_eq!(fourth.frames_since(&third), 1);
+    }
+
+    #[test]
+    fn server_push_reads_every_frame() {
+        let mut vicon = MockVicon {
+            stream_mode: StreamMode::ServerPush,
+            ..MockVicon::new()
+        };
+        let first = read(&mut vicon);
+
+        // Frames captured between reads are buffered...
+        vicon.capture(5);
+        let numbers: Vec<u32> = (0..5).map(|_| read(&mut vicon).number).collect();
+        assert_eq!(
+            numbers,
+            (first.number + 1..=first.number + 5).collect::<Vec<_>>()
+        );
+
+        // ...and reads past the buffer wait for
+        // the next frame to be captured.
+        let next = read(&mut vicon);
+        assert_eq!(next.number, first.number + 6);
+        assert_eq!(vicon.captured, next.number);
+    }
 }
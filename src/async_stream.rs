@@ -0,0 +1,265 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Condvar, Mutex},
+    task::{Context, Poll, Waker},
+    thread,
+};
+
+use futures_core::Stream;
+
+use crate::{stream::RetryBackoff, HasViconHardware, OutputRotation, ViconError, ViconFrame};
+
+/// Strategies for handling frames read faster
+/// than a [`ViconAsyncStream`] is polled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backpressure {
+    /// Queue up to the given number of frames,
+    /// dropping the oldest queued frame to
+    /// make room for each new frame.
+    DropOldest(usize),
+
+    /// Queue up to the given number of frames,
+    /// pausing reads from the hardware until
+    /// the stream is polled again.
+    Block(usize),
+
+    /// Only keep the most recently read frame.
+    KeepLatest,
+}
+
+/// An asynchronous stream of frames read from a thing
+/// that [`HasViconHardware`], which is usable from
+/// any executor (e.g., tokio).
+///
+/// The hardware's blocking reads are run on a
+/// dedicated thread, so they never block the
+/// executor:
+///
+/// ```
+/// use futures::{executor::block_on, StreamExt};
+/// use vicon_sys::{
+///     Backpressure, HasViconHardware, OutputRotation, ViconAsyncStream, ViconError,
+///     ViconFrame,
+/// };
+///
+/// /// A Vicon which sees nothing.
+/// struct EmptyVicon(u32);
+///
+/// impl HasViconHardware for EmptyVicon {
+///     fn read_frame(&mut self, _: OutputRotation) -> Result<ViconFrame, ViconError> {
+///         self.0 += 1;
+///         Ok(ViconFrame::new(self.0, 100.0))
+///     }
+/// }
+///
+/// let mut stream = ViconAsyncStream::new(
+///     EmptyVicon(0),
+///     OutputRotation::Quaternion,
+///     Backpressure::Block(4),
+/// );
+///
+/// // Blocking backpressure never skips frames.
+/// block_on(async {
+///     let first = stream.next().await.unwrap().unwrap();
+///     let second = stream.next().await.unwrap().unwrap();
+///     assert_eq!(second.number, first.number + 1);
+/// });
+/// ```
+///
+/// Failed reads are yielded as errors and retried with
+/// exponential backoff, unless their error [is
+/// fatal](ViconError::is_fatal), in which case the
+/// stream ends after yielding it:
+///
+/// ```
+/// use futures::{executor::block_on, StreamExt};
+/// use vicon_sys::{
+///     Backpressure, HasViconHardware, OutputRotation, ViconAsyncStream, ViconError,
+///     ViconFrame, ViconSdkStatus,
+/// };
+///
+/// /// A Vicon which misses its first frame,
+/// /// and is unplugged after its second.
+/// struct FlakyVicon(u32);
+///
+/// impl HasViconHardware for FlakyVicon {
+///     fn read_frame(&mut self, _: OutputRotation) -> Result<ViconFrame, ViconError> {
+///         self.0 += 1;
+///         let source = match self.0 {
+///             1 => ViconSdkStatus::NoDataFrame,
+///             2 => return Ok(ViconFrame::new(self.0, 100.0)),
+///             _ => ViconSdkStatus::NotConnected,
+///         };
+///         Err(ViconError::SdkError { source })
+///     }
+/// }
+///
+/// let stream = ViconAsyncStream::new(
+///     FlakyVicon(0),
+///     OutputRotation::Quaternion,
+///     Backpressure::Block(4),
+/// );
+///
+/// let results = block_on(stream.collect::<Vec<_>>());
+/// assert_eq!(results.len(), 3);
+/// assert!(!results[0].as_ref().unwrap_err().is_fatal());
+/// assert_eq!(results[1].as_ref().unwrap().number, 2);
+/// assert!(results[2].as_ref().unwrap_err().is_fatal());
+/// ```
+///
+/// The thread is stopped when the stream is dropped,
+/// once its current read (if any) completes; dropping
+/// the stream does not wait for the thread.
+pub struct ViconAsyncStream {
+    shared: Arc<Shared>,
+}
+
+/// State shared between a [`ViconAsyncStream`]
+/// and the thread reading its frames.
+struct Shared {
+    state: Mutex<State>,
+
+    /// Signalled when frames are taken from the queue
+    /// (or the stream is dropped), to resume reads
+    /// paused by [`Backpressure::Block`] (or by
+    /// backing off after a failed read).
+    space_available: Condvar,
+}
+
+struct State {
+    /// Frames (or errors) read but not yet polled.
+    queue: VecDeque<Result<ViconFrame, ViconError>>,
+
+    /// Waker of the task awaiting the next frame, if any.
+    waker: Option<Waker>,
+
+    /// Flag indicating whether the stream was dropped.
+    closed: bool,
+
+    /// Flag indicating whether the reading thread
+    /// has exited (e.g., after panicking).
+    finished: bool,
+}
+
+impl ViconAsyncStream {
+    /// Returns a new stream continuously reading frames
+    /// with rotations of `rotation_type` from `hardware`,
+    /// handling unpolled frames per `backpressure`.
+    pub fn new<H>(
+        mut hardware: H,
+        rotation_type: OutputRotation,
+        backpressure: Backpressure,
+    ) -> Self
+    where
+        H: HasViconHardware + Send + 'static,
+    {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                queue: VecDeque::new(),
+                waker: None,
+                closed: false,
+                finished: false,
+            }),
+            space_available: Condvar::new(),
+        });
+
+        let thread_shared = shared.clone();
+        thread::spawn(move || {
+            let shared = FinishOnDrop(thread_shared);
+            let mut backoff = RetryBackoff::default();
+            loop {
+                if shared.0.state.lock().unwrap().closed {
+                    break;
+                }
+
+                let frame = hardware.read_frame(rotation_type);
+                let fatal = frame.as_ref().is_err_and(ViconError::is_fatal);
+                let delay = backoff.after(&frame);
+
+                let mut state = shared.0.state.lock().unwrap();
+                match backpressure {
+                    Backpressure::DropOldest(capacity) => {
+                        while state.queue.len() >= capacity.max(1) {
+                            state.queue.pop_front();
+                        }
+                    }
+                    Backpressure::Block(capacity) => {
+                        while state.queue.len() >= capacity.max(1) && !state.closed {
+                            state = shared.0.space_available.wait(state).unwrap();
+                        }
+                    }
+                    Backpressure::KeepLatest => state.queue.clear(),
+                }
+
+                if state.closed {
+                    break;
+                }
+
+                state.queue.push_back(frame);
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+
+                // The stream ends once the
+                // error has been polled.
+                if fatal {
+                    break;
+                }
+
+                if !delay.is_zero() {
+                    let _ = shared
+                        .0
+                        .space_available
+                        .wait_timeout_while(state, delay, |state| !state.closed);
+                }
+            }
+        });
+
+        Self { shared }
+    }
+}
+
+impl Stream for ViconAsyncStream {
+    type Item = Result<ViconFrame, ViconError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.shared.state.lock().unwrap();
+
+        if let Some(frame) = state.queue.pop_front() {
+            self.shared.space_available.notify_one();
+            return Poll::Ready(Some(frame));
+        }
+
+        if state.finished {
+            return Poll::Ready(None);
+        }
+
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for ViconAsyncStream {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().closed = true;
+        self.shared.space_available.notify_one();
+    }
+}
+
+/// Marks the reading thread as finished (waking any
+/// waiting task) when dropped, even if it panics.
+struct FinishOnDrop(Arc<Shared>);
+
+impl Drop for FinishOnDrop {
+    fn drop(&mut self) {
+        let mut state = match self.0.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        state.finished = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use nalgebra::Vector3;
+
+use crate::{ViconError, ViconSdkStatus};
+
+/// Enumeration of directions which the axes
+/// of the Vicon's coordinate system can be
+/// mapped onto.
+///
+/// These directions are derived from the
+/// `CDirection` enum listed in the Vicon
+/// SDK's `CTypeDefs.h` file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    Forward,
+    Backward,
+}
+
+impl Direction {
+    /// Returns the unit vector pointing in this
+    /// direction, in a forward-left-up frame.
+    pub fn to_vector(&self) -> Vector3<i32> {
+        match self {
+            Direction::Up => Vector3::z(),
+            Direction::Down => -Vector3::z(),
+            Direction::Left => Vector3::y(),
+            Direction::Right => -Vector3::y(),
+            Direction::Forward => Vector3::x(),
+            Direction::Backward => -Vector3::x(),
+        }
+    }
+}
+
+impl TryFrom<i32> for Direction {
+    type Error = ViconError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Up),
+            1 => Ok(Self::Down),
+            2 => Ok(Self::Left),
+            3 => Ok(Self::Right),
+            4 => Ok(Self::Forward),
+            5 => Ok(Self::Backward),
+            _ => Err(ViconError::OtherError {
+                message: format!("invalid direction: {value}"),
+            }),
+        }
+    }
+}
+
+impl From<Direction> for i32 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Right => 3,
+            Direction::Forward => 4,
+            Direction::Backward => 5,
+        }
+    }
+}
+
+/// A mapping of the Vicon's x, y and z
+/// axes onto [`Direction`]s.
+///
+/// Only right-handed mappings with three distinct
+/// axes are supported by the Vicon SDK, so other
+/// mappings are rejected up front:
+///
+/// ```
+/// use vicon_sys::{AxisMapping, Direction, ViconError, ViconSdkStatus};
+///
+/// // The Vicon's default, z-up mapping.
+/// assert_eq!(AxisMapping::default(), AxisMapping::Z_UP);
+///
+/// // A north-east-down style mapping.
+/// let ned = AxisMapping::new(Direction::Forward, Direction::Right, Direction::Down);
+/// assert!(ned.is_ok());
+///
+/// // Up and down lie along the same line.
+/// let co_linear = AxisMapping::new(Direction::Forward, Direction::Up, Direction::Down);
+/// assert!(matches!(
+///     co_linear,
+///     Err(ViconError::SdkError {
+///         source: ViconSdkStatus::InvalidCoLinearAxes
+///     })
+/// ));
+///
+/// // Forward, left and down form a left-handed frame.
+/// let left_handed = AxisMapping::new(Direction::Forward, Direction::Left, Direction::Down);
+/// assert!(matches!(
+///     left_handed,
+///     Err(ViconError::SdkError {
+///         source: ViconSdkStatus::LeftHandedAxes
+///     })
+/// ));
+/// ```
+///
+/// With the `serde` feature enabled, mappings are
+/// (de)serialized as `[x, y, z]` arrays, and
+/// deserializing an invalid mapping fails with
+/// the error from [`AxisMapping::try_from`],
+/// which names the problem:
+///
+/// ```
+/// use vicon_sys::{AxisMapping, Direction};
+///
+/// let error = AxisMapping::try_from([Direction::Forward, Direction::Up, Direction::Down])
+///     .unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "invalid axis mapping [Forward, Up, Down]: two axes lie along the same line"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "[Direction; 3]", into = "[Direction; 3]")
+)]
+pub struct AxisMapping {
+    x: Direction,
+    y: Direction,
+    z: Direction,
+}
+
+impl AxisMapping {
+    /// The Vicon's default mapping, with
+    /// the z axis pointing up.
+    pub const Z_UP: Self = Self {
+        x: Direction::Forward,
+        y: Direction::Left,
+        z: Direction::Up,
+    };
+
+    /// A mapping with the y axis pointing up,
+    /// as used by many graphics applications.
+    pub const Y_UP: Self = Self {
+        x: Direction::Forward,
+        y: Direction::Up,
+        z: Direction::Right,
+    };
+
+    /// Returns a new mapping of the Vicon's
+    /// axes onto `x`, `y` and `z`.
+    ///
+    /// Returns [`ViconSdkStatus::InvalidCoLinearAxes`] if
+    /// any two axes lie along the same line, and
+    /// [`ViconSdkStatus::LeftHandedAxes`] if the axes
+    /// form a left-handed coordinate system.
+    pub fn new(x: Direction, y: Direction, z: Direction) -> Result<Self, ViconError> {
+        let (x_vector, y_vector, z_vector) = (x.to_vector(), y.to_vector(), z.to_vector());
+
+        let co_linear = |a: &Vector3<i32>, b: &Vector3<i32>| a.dot(b) != 0;
+        if co_linear(&x_vector, &y_vector)
+            || co_linear(&y_vector, &z_vector)
+            || co_linear(&z_vector, &x_vector)
+        {
+            return Err(ViconError::SdkError {
+                source: ViconSdkStatus::InvalidCoLinearAxes,
+            });
+        }
+
+        if x_vector.cross(&y_vector) != z_vector {
+            return Err(ViconError::SdkError {
+                source: ViconSdkStatus::LeftHandedAxes,
+            });
+        }
+
+        Ok(Self { x, y, z })
+    }
+
+    /// Returns the direction of the x axis.
+    pub fn x(&self) -> Direction {
+        self.x
+    }
+
+    /// Returns the direction of the y axis.
+    pub fn y(&self) -> Direction {
+        self.y
+    }
+
+    /// Returns the direction of the z axis.
+    pub fn z(&self) -> Direction {
+        self.z
+    }
+}
+
+impl TryFrom<[Direction; 3]> for AxisMapping {
+    type Error = ViconError;
+
+    fn try_from([x, y, z]: [Direction; 3]) -> Result<Self, Self::Error> {
+        Self::new(x, y, z).map_err(|error| {
+            let problem = match error {
+                ViconError::SdkError {
+                    source: ViconSdkStatus::InvalidCoLinearAxes,
+                } => "two axes lie along the same line",
+                ViconError::SdkError {
+                    source: ViconSdkStatus::LeftHandedAxes,
+                } => "the axes form a left-handed coordinate system",
+                error => return error,
+            };
+
+            ViconError::OtherError {
+                message: format!("invalid axis mapping [{x:?}, {y:?}, {z:?}]: {problem}"),
+            }
+        })
+    }
+}
+
+impl From<AxisMapping> for [Direction; 3] {
+    fn from(axis_mapping: AxisMapping) -> Self {
+        [axis_mapping.x, axis_mapping.y, axis_mapping.z]
+    }
+}
+
+impl Default for AxisMapping {
+    fn default() -> Self {
+        Self::Z_UP
+    }
+}
+
+/// Enumeration of the "up" axes
+/// which a Vicon server may use.
+///
+/// These orientations are derived from the
+/// `CServerOrientation` enum listed in the
+/// Vicon SDK's `CTypeDefs.h` file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServerOrientation {
+    /// The server's orientation is not known.
+    Unknown,
+
+    /// The server's y axis points up.
+    YUp,
+
+    /// The server's z axis points up.
+    ZUp,
+}
+
+impl From<i32> for ServerOrientation {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => Self::YUp,
+            2 => Self::ZUp,
+            _ => Self::Unknown,
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use std::time::Duration;
+
+use crate::{AxisMapping, StreamMode};
+
+/// Default number of times a connection to a Vicon
+/// data stream is retried after failing.
+pub const MAX_CONNECT_RETRIES: usize = 3;
+
+/// Default timeout (in milliseconds) used
+/// when connecting to a Vicon data stream.
+pub const MAX_CONNECT_TIMEOUT: u32 = 1000;
+
+/// Configuration of a connection
+/// to a Vicon data stream.
+///
+/// With the `serde` feature enabled, configurations
+/// can be deserialized from (e.g.) TOML or JSON files;
+/// omitted fields take their default values, and
+/// durations are given in milliseconds.
+///
+/// ```
+/// use std::time::Duration;
+/// use vicon_sys::{StreamMode, ViconConfig};
+///
+/// let config = ViconConfig {
+///     stream_mode: StreamMode::ServerPush,
+///     retry_backoff: Duration::from_millis(250),
+///     subject_filter: vec!["mob_6".to_string()],
+///     ..ViconConfig::new("192.168.1.1:801")
+/// };
+///
+/// assert_eq!(config.hostname, "192.168.1.1:801");
+/// assert_eq!(config.connect_retries, 3);
+/// assert!(config.data_types.segments);
+/// ```
+///
+/// Deserializing a configuration with
+/// the `serde` feature enabled:
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// use std::time::Duration;
+/// use vicon_sys::{AxisMapping, Direction, ViconConfig};
+///
+/// let config: ViconConfig = serde_json::from_str(
+///     r#"{
+///         "hostname": "192.168.1.1:801",
+///         "retry_backoff": 250,
+///         "axis_mapping": ["Forward", "Right", "Down"],
+///         "data_types": { "unlabeled_markers": true }
+///     }"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(config.retry_backoff, Duration::from_millis(250));
+/// assert_eq!(
+///     config.axis_mapping,
+///     AxisMapping::new(Direction::Forward, Direction::Right, Direction::Down).unwrap()
+/// );
+///
+/// // Omitted fields take their default values.
+/// assert_eq!(config.connect_timeout, ViconConfig::default().connect_timeout);
+/// assert!(config.data_types.unlabeled_markers);
+/// assert!(config.data_types.segments);
+///
+/// // Durations round-trip as milliseconds.
+/// let json = serde_json::to_value(&config).unwrap();
+/// assert_eq!(json["retry_backoff"], 250);
+/// assert_eq!(serde_json::from_value::<ViconConfig>(json).unwrap(), config);
+///
+/// // Invalid axis mappings are rejected.
+/// let left_handed = serde_json::from_str::<ViconConfig>(
+///     r#"{ "axis_mapping": ["Forward", "Left", "Down"] }"#,
+/// );
+/// assert!(left_handed.unwrap_err().to_string().contains("left-handed"));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ViconConfig {
+    /// Hostname of the Vicon data stream, which may
+    /// optionally include a port suffix (e.g.,
+    /// `192.168.1.1:801`).
+    pub hostname: String,
+
+    /// Multicast group (e.g., `224.0.0.0:44801`) to
+    /// receive the data stream from instead of
+    /// connecting to the Vicon directly, in which
+    /// case `hostname` is the IP address of the
+    /// local adapter to receive on.
+    pub multicast_group: Option<String>,
+
+    /// Timeout of each connection attempt.
+    #[cfg_attr(feature = "serde", serde(with = "duration_millis"))]
+    pub connect_timeout: Duration,
+
+    /// Number of times a failed connection
+    /// attempt is retried before giving up.
+    pub connect_retries: usize,
+
+    /// Delay before the first retry of a failed
+    /// connection attempt, which doubles with
+    /// each subsequent retry.
+    #[cfg_attr(feature = "serde", serde(with = "duration_millis"))]
+    pub retry_backoff: Duration,
+
+    /// Delay after connecting before the system
+    /// is used, giving the data stream time to
+    /// start delivering frames.
+    #[cfg_attr(feature = "serde", serde(with = "duration_millis"))]
+    pub settle_time: Duration,
+
+    /// Mode in which frames are
+    /// streamed from the Vicon.
+    pub stream_mode: StreamMode,
+
+    /// Mapping of the Vicon's axes.
+    pub axis_mapping: AxisMapping,
+
+    /// Number of frames buffered by the
+    /// client, or `None` for the SDK default.
+    pub buffer_size: Option<u32>,
+
+    /// Types of data streamed from the Vicon.
+    pub data_types: ViconDataTypes,
+
+    /// Names of the only subjects streamed from
+    /// the Vicon, or empty to stream all subjects.
+    pub subject_filter: Vec<String>,
+
+    /// Multicast transmission to start once
+    /// connected, if any.
+    pub transmit_multicast: Option<ViconMulticast>,
+
+    /// Minimum quality below which subjects are
+    /// flagged as [`crate::ViconSubject::low_quality`],
+    /// or `None` to never flag subjects.
+    pub quality_threshold: Option<f64>,
+}
+
+impl ViconConfig {
+    /// Returns the default configuration
+    /// for a connection to `hostname`.
+    pub fn new(hostname: &str) -> Self {
+        Self {
+            hostname: hostname.to_string(),
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for ViconConfig {
+    fn default() -> Self {
+        Self {
+            hostname: "localhost".to_string(),
+            multicast_group: None,
+            connect_timeout: Duration::from_millis(MAX_CONNECT_TIMEOUT.into()),
+            connect_retries: MAX_CONNECT_RETRIES,
+            retry_backoff: Duration::ZERO,
+            settle_time: Duration::from_millis(1000),
+            stream_mode: StreamMode::default(),
+            axis_mapping: AxisMapping::default(),
+            buffer_size: None,
+            data_types: ViconDataTypes::default(),
+            subject_filter: Vec::new(),
+            transmit_multicast: None,
+            quality_threshold: None,
+        }
+    }
+}
+
+/// Addresses via which a Vicon
+/// transmits its data stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViconMulticast {
+    /// IP address of the Vicon's adapter
+    /// to transmit from.
+    pub server_ip: String,
+
+    /// Multicast group (e.g., `224.0.0.0:44801`)
+    /// to transmit to.
+    pub multicast_group: String,
+}
+
+/// Flags indicating which types of
+/// data are streamed from the Vicon.
+///
+/// Only segment and marker data are streamed by
+/// default; disabling unused types reduces the
+/// bandwidth required by the data stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ViconDataTypes {
+    /// Subject segments.
+    pub segments: bool,
+
+    /// Subject segments, sent via a more compact
+    /// protocol which replaces `segments` and
+    /// carries only global poses.
+    pub lightweight_segments: bool,
+
+    /// Labeled subject markers.
+    pub markers: bool,
+
+    /// Markers not assigned to any subject.
+    pub unlabeled_markers: bool,
+
+    /// Rays from cameras to the markers they observe.
+    pub marker_rays: bool,
+
+    /// Centroids of blobs seen by each camera.
+    pub centroids: bool,
+
+    /// Outputs of devices (e.g., force plates).
+    pub devices: bool,
+
+    /// Greyscale blobs seen by each camera.
+    pub greyscale: bool,
+
+    /// Debugging information from the Vicon.
+    pub debug: bool,
+}
+
+impl Default for ViconDataTypes {
+    fn default() -> Self {
+        Self {
+            segments: true,
+            lightweight_segments: false,
+            markers: true,
+            unlabeled_markers: false,
+            marker_rays: false,
+            centroids: false,
+            devices: false,
+            greyscale: false,
+            debug: false,
+        }
+    }
+}
+
+/// (De)serializes [`Duration`]s as
+/// whole numbers of milliseconds.
+#[cfg(feature = "serde")]
+mod duration_millis {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_millis().try_into().unwrap_or(u64::MAX))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Ok(Duration::from_millis(u64::deserialize(deserializer)?))
+    }
+}
@@ -1,5 +1,9 @@
-use nalgebra::{UnitQuaternion, Vector3};
+use nalgebra::{Rotation3, UnitQuaternion, Vector3};
 use snafu::Snafu;
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
 pub mod sys;
 
 #[cfg(target_os = "linux")]
@@ -7,14 +11,222 @@ pub mod sys;
 /// for use with a real Vicon system.
 pub mod sys;
 
+mod axes;
+pub use axes::{AxisMapping, Direction, ServerOrientation};
+
+mod config;
+pub use config::{
+    ViconConfig, ViconDataTypes, ViconMulticast, MAX_CONNECT_RETRIES, MAX_CONNECT_TIMEOUT,
+};
+
+mod stream;
+pub use stream::ViconStream;
+
+#[cfg(feature = "async")]
+mod async_stream;
+#[cfg(feature = "async")]
+pub use async_stream::{Backpressure, ViconAsyncStream};
+
+mod timecode;
+pub use timecode::{Timecode, TimecodeStandard};
+
 /// A thing that can read from a Vicon data stream.
 pub trait HasViconHardware {
+    /// Returns the next available frame from the
+    /// system, including all identified [`ViconSubject`]s.
+    ///
+    /// Which frame is "next", and whether this blocks
+    /// waiting for it, depends on the system's
+    /// [`StreamMode`] (if any).
+    fn read_frame(&mut self, rotation_type: OutputRotation) -> Result<ViconFrame, ViconError>;
+
     /// Returns a list of all identified [`ViconSubject`]s
     /// in the next available frame from the system.
     fn read_frame_subjects(
         &mut self,
         rotation_type: OutputRotation,
-    ) -> Result<Vec<ViconSubject>, ViconError>;
+    ) -> Result<Vec<ViconSubject>, ViconError> {
+        Ok(self.read_frame(rotation_type)?.subjects)
+    }
+
+    /// Returns a list of all unlabeled markers (i.e., markers
+    /// not assigned to any [`ViconSubject`]) in the frame
+    /// most recently returned by [`HasViconHardware::read_frame`].
+    ///
+    /// This does not read a new frame, so the markers
+    /// always match the subjects of that frame.
+    ///
+    /// By default, this returns [`ViconSdkStatus::Unsupported`].
+    fn read_frame_unlabeled_markers(&mut self) -> Result<Vec<ViconPointMarker>, ViconError> {
+        Err(ViconError::SdkError {
+            source: ViconSdkStatus::Unsupported,
+        })
+    }
+
+    /// Returns a flat list of all labeled markers (i.e.,
+    /// markers assigned to any [`ViconSubject`]) in the
+    /// next available frame from the system, along with
+    /// the number and receipt time of that frame.
+    ///
+    /// Unlike [`HasViconHardware::read_frame_subjects`],
+    /// this does not look up any subject, segment or
+    /// marker names, making it suitable for high-rate
+    /// consumers which only need a point cloud.
+    ///
+    /// By default, this returns [`ViconSdkStatus::Unsupported`].
+    fn read_frame_labeled_markers(&mut self) -> Result<ViconMarkerFrame, ViconError> {
+        Err(ViconError::SdkError {
+            source: ViconSdkStatus::Unsupported,
+        })
+    }
+
+    /// Restricts all subsequently read frames to the
+    /// subjects named in `subject_names`, replacing
+    /// any previously set subject filter.
+    ///
+    /// Returns an error, leaving the subject filter
+    /// unchanged, if any of the names is invalid.
+    /// Systems which only accept names once frames
+    /// arrive (like the Vicon) keep retrying names
+    /// they haven't accepted on subsequent reads.
+    ///
+    /// By default, this returns [`ViconSdkStatus::Unsupported`].
+    fn set_subject_filter(&mut self, subject_names: &[&str]) -> Result<(), ViconError> {
+        let _ = subject_names;
+        Err(ViconError::SdkError {
+            source: ViconSdkStatus::Unsupported,
+        })
+    }
+
+    /// Clears the subject filter, so all subsequently
+    /// read frames include every subject.
+    ///
+    /// By default, this returns [`ViconSdkStatus::Unsupported`].
+    fn clear_subject_filter(&mut self) -> Result<(), ViconError> {
+        Err(ViconError::SdkError {
+            source: ViconSdkStatus::Unsupported,
+        })
+    }
+}
+
+/// A single frame read by a thing
+/// that [`HasViconHardware`].
+#[derive(Debug, Clone)]
+pub struct ViconFrame {
+    /// The frame's number, which increases
+    /// by one for each frame captured by
+    /// the system.
+    pub number: u32,
+
+    /// The frame's hardware (camera) frame number,
+    /// or `None` if the system does not report it.
+    pub hardware_number: Option<u32>,
+
+    /// The system's frame rate in Hz.
+    pub frame_rate: f64,
+
+    /// The local time at which the frame was received.
+    pub received_at: SystemTime,
+
+    /// The frame's timecode, or `None` if the
+    /// system has no timecode source.
+    pub timecode: Option<Timecode>,
+
+    /// The frame's latency, or `None` if
+    /// the system does not report it.
+    pub latency: Option<ViconLatency>,
+
+    /// All identified subjects in the frame.
+    pub subjects: Vec<ViconSubject>,
+}
+
+impl ViconFrame {
+    /// Returns a new frame numbered `number`, received
+    /// now from a system running at `frame_rate` Hz,
+    /// with no subjects, timecode or latency.
+    ///
+    /// ```
+    /// use vicon_sys::ViconFrame;
+    ///
+    /// let frame = ViconFrame::new(10, 100.0);
+    /// assert_eq!(frame.number, 10);
+    /// assert_eq!(frame.hardware_number, None);
+    /// assert!(frame.subjects.is_empty());
+    /// ```
+    pub fn new(number: u32, frame_rate: f64) -> Self {
+        Self {
+            number,
+            hardware_number: None,
+            frame_rate,
+            received_at: SystemTime::now(),
+            timecode: None,
+            latency: None,
+            subjects: Vec::new(),
+        }
+    }
+
+    /// Returns the number of frames captured by the
+    /// system between the `previous` frame and this one.
+    ///
+    /// A result of `1` indicates consecutive frames, `0`
+    /// a duplicated frame, and anything greater than `1`
+    /// indicates that frames were dropped.
+    ///
+    /// ```
+    /// # use vicon_sys::ViconFrame;
+    /// # let frame = |number| ViconFrame::new(number, 100.0);
+    /// assert_eq!(frame(11).frames_since(&frame(10)), 1);
+    /// assert_eq!(frame(10).frames_since(&frame(10)), 0);
+    /// assert_eq!(frame(14).frames_since(&frame(10)), 4);
+    /// ```
+    pub fn frames_since(&self, previous: &ViconFrame) -> i64 {
+        i64::from(self.number) - i64::from(previous.number)
+    }
+
+    /// Returns the estimated local time at which the
+    /// frame was captured, by back-dating the time the
+    /// frame was received by the frame's total latency.
+    ///
+    /// ```
+    /// # use std::time::{Duration, SystemTime};
+    /// # use vicon_sys::{ViconFrame, ViconLatency};
+    /// let received_at = SystemTime::now();
+    /// let frame = ViconFrame {
+    ///     received_at,
+    ///     latency: Some(ViconLatency {
+    ///         total: Duration::from_millis(8),
+    ///         samples: vec![],
+    ///     }),
+    ///     ..ViconFrame::new(0, 100.0)
+    /// };
+    /// assert_eq!(
+    ///     frame.estimated_capture_time(),
+    ///     received_at - Duration::from_millis(8)
+    /// );
+    /// ```
+    ///
+    /// If the frame has no latency, this
+    /// returns the time it was received.
+    pub fn estimated_capture_time(&self) -> SystemTime {
+        match &self.latency {
+            Some(latency) => self.received_at - latency.total,
+            None => self.received_at,
+        }
+    }
+}
+
+/// The latency of a single [`ViconFrame`],
+/// as reported by the Vicon.
+#[derive(Debug, Clone, Default)]
+pub struct ViconLatency {
+    /// The total latency between the frame's
+    /// capture and its transmission to clients.
+    pub total: Duration,
+
+    /// The individual (named) stages of the
+    /// frame's latency, in the order they are
+    /// reported by the Vicon.
+    pub samples: Vec<(String, Duration)>,
 }
 
 /// A single subject identified in a frame
@@ -35,32 +247,694 @@ pub struct ViconSubject {
 
     /// Flag indicating whether the subject is occluded.
     pub occluded: bool,
+
+    /// The quality of the subject's fit, as
+    /// reported by the Vicon, or `None` if
+    /// the server does not report it.
+    pub quality: Option<f64>,
+
+    /// Flag indicating whether the subject's
+    /// `quality` fell below the configured
+    /// quality threshold.
+    pub low_quality: bool,
+
+    /// All of the subject's segments, in the
+    /// order they are reported by the Vicon.
+    ///
+    /// The subject's `origin`, `rotation` and
+    /// `occluded` fields mirror the _zeroth_
+    /// segment in this list.
+    pub segments: Vec<ViconSegment>,
+
+    /// The kinematic tree formed by the
+    /// subject's `segments`, which is shared
+    /// between frames.
+    pub hierarchy: Arc<SegmentTree>,
+
+    /// All of the subject's labeled markers, in
+    /// the order they are reported by the Vicon.
+    pub markers: Vec<ViconMarker>,
+}
+
+impl ViconSubject {
+    /// Returns `true` iff the subject's pose can be
+    /// trusted: it is neither occluded nor flagged
+    /// as a low-quality solve.
+    pub fn is_trustworthy(&self) -> bool {
+        !self.occluded && !self.low_quality
+    }
+
+    /// Returns the subject's segment named `name`, if any.
+    pub fn segment(&self, name: &str) -> Option<&ViconSegment> {
+        self.segments.iter().find(|segment| segment.name == name)
+    }
+
+    /// Returns the subject's root segment, if any.
+    pub fn root_segment(&self) -> Option<&ViconSegment> {
+        self.hierarchy.root().map(|i| &self.segments[i])
+    }
+
+    /// Returns the parent of the segment named `name`,
+    /// or `None` if the segment is a root segment
+    /// or does not exist.
+    pub fn parent_segment(&self, name: &str) -> Option<&ViconSegment> {
+        let index = self.segment_index(name)?;
+        self.hierarchy.parent(index).map(|i| &self.segments[i])
+    }
+
+    /// Returns the children of the segment named `name`.
+    pub fn child_segments(&self, name: &str) -> Vec<&ViconSegment> {
+        self.segment_index(name)
+            .map(|index| {
+                self.hierarchy
+                    .children(index)
+                    .iter()
+                    .map(|&i| &self.segments[i])
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the chain of segments from the subject's
+    /// root to the segment named `name` (inclusive), or
+    /// an empty list if the segment does not exist.
+    pub fn segment_chain(&self, name: &str) -> Vec<&ViconSegment> {
+        self.segment_index(name)
+            .map(|index| {
+                self.hierarchy
+                    .chain(index)
+                    .into_iter()
+                    .map(|i| &self.segments[i])
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the subject's occluded markers,
+    /// e.g. to detect marker dropout before
+    /// the subject itself becomes occluded.
+    pub fn occluded_markers(&self) -> impl Iterator<Item = &ViconMarker> {
+        self.markers.iter().filter(|marker| marker.occluded)
+    }
+
+    /// Returns the index of the segment named `name`
+    /// within the subject's list of `segments`.
+    fn segment_index(&self, name: &str) -> Option<usize> {
+        self.segments
+            .iter()
+            .position(|segment| segment.name == name)
+    }
+}
+
+/// A single segment of a [`ViconSubject`].
+///
+/// Rigid bodies typically have exactly one
+/// segment, while articulated subjects (e.g.,
+/// a manipulator or a legged robot) have one
+/// segment per link.
+#[derive(Debug, Clone)]
+pub struct ViconSegment {
+    /// The segment's name.
+    pub name: String,
+
+    /// The name of the segment's parent segment,
+    /// or `None` if this is a root segment.
+    pub parent: Option<String>,
+
+    /// The segment's position in meters
+    /// relative to the origin of the
+    /// motion capture volume.
+    pub origin: Vector3<f64>,
+
+    /// The segment's rotation relative to
+    /// the motion capture volume.
+    pub rotation: RotationType,
+
+    /// Flag indicating whether the segment is occluded.
+    pub occluded: bool,
+
+    /// The segment's position in meters relative
+    /// to its parent segment, or `None` if the
+    /// data stream does not carry local poses
+    /// (e.g., when streaming lightweight segments).
+    pub local_origin: Option<Vector3<f64>>,
+
+    /// The segment's rotation relative to its
+    /// parent segment, or `None` if the data
+    /// stream does not carry local poses
+    /// (e.g., when streaming lightweight segments).
+    pub local_rotation: Option<RotationType>,
+}
+
+/// A single labeled marker of a [`ViconSubject`].
+#[derive(Debug, Clone)]
+pub struct ViconMarker {
+    /// The marker's name.
+    pub name: String,
+
+    /// The name of the segment the marker is
+    /// attached to, or `None` if it is not
+    /// attached to any segment.
+    pub parent: Option<String>,
+
+    /// The marker's position in meters
+    /// relative to the origin of the
+    /// motion capture volume.
+    pub position: Vector3<f64>,
+
+    /// Flag indicating whether the marker is occluded.
+    ///
+    /// The `position` of an occluded
+    /// marker is not meaningful.
+    pub occluded: bool,
+}
+
+/// A single marker reported independently
+/// of any [`ViconSubject`].
+#[derive(Debug, Clone)]
+pub struct ViconPointMarker {
+    /// The marker's position in meters
+    /// relative to the origin of the
+    /// motion capture volume.
+    pub position: Vector3<f64>,
+
+    /// The ID of the trajectory the marker
+    /// belongs to, which identifies the same
+    /// physical marker across frames.
+    pub trajectory_id: u32,
+}
+
+/// The markers of a single frame read by a
+/// thing that [`HasViconHardware`], without
+/// the frame's subjects.
+#[derive(Debug, Clone)]
+pub struct ViconMarkerFrame {
+    /// The frame's number (see [`ViconFrame::number`]).
+    pub number: u32,
+
+    /// The local time at which the frame was received.
+    pub received_at: SystemTime,
+
+    /// All markers in the frame.
+    pub markers: Vec<ViconPointMarker>,
 }
 
-#[derive(Debug)]
+/// The static (model-definition) description
+/// of a [`ViconSubject`], such as the contents
+/// of the subject's VSK file.
+///
+/// Unlike a [`ViconSubject`], a subject's
+/// model does not change between frames.
+#[derive(Debug, Clone)]
+pub struct ViconSubjectModel {
+    /// The subject's name.
+    pub name: String,
+
+    /// The static models of all of the subject's
+    /// segments, in the order they are reported
+    /// by the Vicon.
+    pub segments: Vec<ViconSegmentModel>,
+}
+
+/// The static (model-definition) description
+/// of a single [`ViconSegment`].
+#[derive(Debug, Clone)]
+pub struct ViconSegmentModel {
+    /// The segment's name.
+    pub name: String,
+
+    /// The segment's static position in meters
+    /// relative to its parent segment.
+    pub translation: Vector3<f64>,
+
+    /// The segment's static rotation
+    /// relative to its parent segment.
+    pub rotation: RotationType,
+
+    /// The segment's static scale along each
+    /// of its axes, or `None` if the Vicon
+    /// does not report it.
+    pub scale: Option<Vector3<f64>>,
+}
+
+/// The kinematic tree formed by the
+/// segments of a [`ViconSubject`].
+///
+/// Segments are identified by their index
+/// within [`ViconSubject::segments`].
+///
+/// ```
+/// use vicon_sys::{SegmentTree, ViconSegment};
+///
+/// # let segment = |name: &str, parent: Option<&str>| ViconSegment {
+/// #     name: name.to_string(),
+/// #     parent: parent.map(str::to_string),
+/// #     origin: nalgebra::Vector3::zeros(),
+/// #     rotation: vicon_sys::RotationType::Quaternion(nalgebra::UnitQuaternion::identity()),
+/// #     occluded: false,
+/// #     local_origin: None,
+/// #     local_rotation: None,
+/// # };
+/// let segments = [
+///     segment("pelvis", None),
+///     segment("thigh", Some("pelvis")),
+///     segment("shin", Some("thigh")),
+///     segment("spine", Some("pelvis")),
+/// ];
+///
+/// let tree = SegmentTree::from_parents(&segments);
+/// assert_eq!(tree.root(), Some(0));
+/// assert_eq!(tree.parent(2), Some(1));
+/// assert_eq!(tree.parent(0), None);
+/// assert_eq!(tree.children(0), &[1, 3]);
+/// assert_eq!(tree.chain(2), vec![0, 1, 2]);
+///
+/// // Indices outside the tree have no relatives.
+/// assert_eq!(tree.parent(4), None);
+/// assert!(tree.children(4).is_empty());
+/// assert!(tree.chain(4).is_empty());
+///
+/// // The same tree can be built from each
+/// // segment's children, as the Vicon SDK
+/// // reports them.
+/// let children = [
+///     vec!["thigh".to_string(), "spine".to_string()],
+///     vec!["shin".to_string()],
+///     vec![],
+///     vec![],
+/// ];
+/// let tree = SegmentTree::from_children(&segments, "pelvis", &children);
+/// assert_eq!(tree.root(), Some(0));
+/// assert_eq!(tree.parent(3), Some(0));
+/// assert_eq!(tree.chain(2), vec![0, 1, 2]);
+///
+/// // Chains through malformed (cyclic)
+/// // trees stop before repeating.
+/// let cyclic = SegmentTree::from_parents(&[segment("a", Some("b")), segment("b", Some("a"))]);
+/// assert_eq!(cyclic.root(), None);
+/// assert_eq!(cyclic.chain(0), vec![1, 0]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SegmentTree {
+    /// Index of the root segment.
+    root: Option<usize>,
+
+    /// Index of each segment's parent.
+    parents: Vec<Option<usize>>,
+
+    /// Indices of each segment's children.
+    children: Vec<Vec<usize>>,
+}
+
+impl SegmentTree {
+    /// Builds a tree from the [`ViconSegment::parent`]
+    /// names of `segments`.
+    ///
+    /// The first segment without a parent
+    /// is used as the root of the tree.
+    pub fn from_parents(segments: &[ViconSegment]) -> Self {
+        let index_of = |name: &str| segments.iter().position(|s| s.name == name);
+
+        let parents: Vec<Option<usize>> = segments
+            .iter()
+            .map(|segment| segment.parent.as_deref().and_then(index_of))
+            .collect();
+
+        let mut children = vec![Vec::new(); segments.len()];
+        for (child, parent) in parents.iter().enumerate() {
+            if let Some(parent) = parent {
+                children[*parent].push(child);
+            }
+        }
+
+        Self {
+            root: segments.iter().position(|s| s.parent.is_none()),
+            parents,
+            children,
+        }
+    }
+
+    /// Returns the number of segments in the tree.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Returns `true` iff the tree has no segments.
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Builds a tree from the name of the `root`
+    /// segment and the names of the `children`
+    /// of each of the `segments`.
+    ///
+    /// Names which do not match any of the
+    /// `segments` are ignored.
+    pub fn from_children(segments: &[ViconSegment], root: &str, children: &[Vec<String>]) -> Self {
+        let index_of = |name: &str| segments.iter().position(|s| s.name == name);
+
+        let children: Vec<Vec<usize>> = children
+            .iter()
+            .map(|names| names.iter().filter_map(|name| index_of(name)).collect())
+            .collect();
+
+        let mut parents = vec![None; segments.len()];
+        for (parent, children) in children.iter().enumerate() {
+            for child in children {
+                parents[*child] = Some(parent);
+            }
+        }
+
+        Self {
+            root: index_of(root),
+            parents,
+            children,
+        }
+    }
+
+    /// Returns the index of the root segment, if any.
+    pub fn root(&self) -> Option<usize> {
+        self.root
+    }
+
+    /// Returns the index of the parent of
+    /// the segment at `index`, if any.
+    pub fn parent(&self, index: usize) -> Option<usize> {
+        self.parents.get(index).copied().flatten()
+    }
+
+    /// Returns the indices of the children
+    /// of the segment at `index`.
+    pub fn children(&self, index: usize) -> &[usize] {
+        self.children.get(index).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Returns the indices of the segments on the
+    /// path from the root of the tree to the
+    /// segment at `index` (inclusive).
+    pub fn chain(&self, index: usize) -> Vec<usize> {
+        if index >= self.parents.len() {
+            return Vec::new();
+        }
+
+        let mut chain = vec![index];
+        let mut current = index;
+        while let Some(parent) = self.parent(current) {
+            // Guard against malformed (cyclic) trees.
+            if chain.contains(&parent) {
+                break;
+            }
+            chain.push(parent);
+            current = parent;
+        }
+        chain.reverse();
+        chain
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputRotation {
     Euler,
     Quaternion,
+    Matrix,
+    Helical,
+}
+
+/// Modes in which frames can be streamed
+/// from the Vicon to the client.
+///
+/// The mode determines what each call to
+/// [`HasViconHardware::read_frame`] (and
+/// [`HasViconHardware::read_frame_subjects`])
+/// waits for and returns:
+///
+/// ```
+/// use vicon_sys::StreamMode;
+///
+/// // Frames are pulled on request by default.
+/// assert_eq!(StreamMode::default(), StreamMode::ClientPull);
+///
+/// // Only pre-fetching and pushing hide the
+/// // network round trip from each read.
+/// assert!(!StreamMode::ClientPull.is_buffered());
+/// assert!(StreamMode::ClientPullPreFetch.is_buffered());
+/// assert!(StreamMode::ServerPush.is_buffered());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StreamMode {
+    /// Each read requests the latest frame from the
+    /// Vicon and blocks until it arrives, adding a
+    /// network round trip to every read.
+    #[default]
+    ClientPull,
+
+    /// Each read returns a frame requested by the
+    /// previous read and requests the next one,
+    /// hiding the round trip at the cost of the
+    /// returned frame possibly being stale.
+    ClientPullPreFetch,
+
+    /// The Vicon sends every frame as it is captured;
+    /// each read returns the next buffered frame,
+    /// blocking until one arrives if none are
+    /// buffered. Reads must keep up with the
+    /// Vicon's frame rate to avoid falling behind.
+    ServerPush,
+}
+
+impl StreamMode {
+    /// Returns `true` iff frames are sent by the
+    /// Vicon ahead of the read which returns them.
+    pub fn is_buffered(&self) -> bool {
+        !matches!(self, StreamMode::ClientPull)
+    }
 }
 
+/// A rotation in one of the representations
+/// available from the Vicon SDK.
+///
+/// Any representation can be converted to any
+/// other via [`RotationType::to_output`] or one
+/// of the `to_*` accessors, so a single frame
+/// read can serve several consumers (e.g., a
+/// controller wanting quaternions and a logger
+/// wanting Euler angles).
 #[derive(Debug, Clone)]
 pub enum RotationType {
+    /// Euler angles in radians, following the Vicon
+    /// SDK's `EulerXYZ` convention: the rotation is
+    /// `Rx(x) * Ry(y) * Rz(z)`.
+    ///
+    /// Note that this differs from nalgebra's
+    /// roll/pitch/yaw convention, which composes
+    /// the same axes in the opposite order.
     Euler(Vector3<f64>),
+
+    /// A unit quaternion.
     Quaternion(UnitQuaternion<f64>),
+
+    /// A rotation matrix.
+    Matrix(Rotation3<f64>),
+
+    /// A helical (axis-angle) vector, whose direction
+    /// is the axis of rotation and whose magnitude is
+    /// the angle of rotation in radians.
+    Helical(Vector3<f64>),
+}
+
+impl RotationType {
+    /// Returns this rotation as a unit quaternion.
+    ///
+    /// ```
+    /// use nalgebra::{UnitQuaternion, Vector3};
+    /// use vicon_sys::RotationType;
+    ///
+    /// // Vicon's EulerXYZ angles compose as Rx * Ry * Rz.
+    /// let (x, y, z) = (0.1, -0.4, 1.2);
+    /// let expected = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), x)
+    ///     * UnitQuaternion::from_axis_angle(&Vector3::y_axis(), y)
+    ///     * UnitQuaternion::from_axis_angle(&Vector3::z_axis(), z);
+    ///
+    /// let quaternion = RotationType::Euler(Vector3::new(x, y, z)).to_quaternion();
+    /// assert!(quaternion.angle_to(&expected) < 1e-9);
+    /// ```
+    pub fn to_quaternion(&self) -> UnitQuaternion<f64> {
+        match self {
+            RotationType::Euler(euler) => {
+                UnitQuaternion::from_axis_angle(&Vector3::x_axis(), euler.x)
+                    * UnitQuaternion::from_axis_angle(&Vector3::y_axis(), euler.y)
+                    * UnitQuaternion::from_axis_angle(&Vector3::z_axis(), euler.z)
+            }
+            RotationType::Quaternion(quaternion) => *quaternion,
+            RotationType::Matrix(matrix) => UnitQuaternion::from_rotation_matrix(matrix),
+            RotationType::Helical(helical) => UnitQuaternion::from_scaled_axis(*helical),
+        }
+    }
+
+    /// Returns this rotation as a rotation matrix.
+    ///
+    /// ```
+    /// use nalgebra::{Matrix3, Vector3};
+    /// use vicon_sys::RotationType;
+    ///
+    /// // A quarter turn about Z, as reported by the Vicon
+    /// // SDK's (row-major) GetSegmentGlobalRotationMatrix.
+    /// let expected = Matrix3::from_row_slice(&[
+    ///     0.0, -1.0, 0.0, //
+    ///     1.0, 0.0, 0.0, //
+    ///     0.0, 0.0, 1.0,
+    /// ]);
+    ///
+    /// let euler = RotationType::Euler(Vector3::new(0.0, 0.0, std::f64::consts::FRAC_PI_2));
+    /// assert!((euler.to_matrix().matrix() - expected).norm() < 1e-9);
+    /// ```
+    ///
+    /// Rotations about all three axes compose as
+    /// `Rx * Ry * Rz`, in either direction:
+    ///
+    /// ```
+    /// use nalgebra::{Matrix3, Rotation3, Vector3};
+    /// use vicon_sys::RotationType;
+    ///
+    /// let euler = Vector3::new(0.1, -0.4, 1.2);
+    /// let expected = Matrix3::from_row_slice(&[
+    ///     0.333754, -0.858465, -0.389418, //
+    ///     0.913295, 0.396782, -0.091953, //
+    ///     0.233452, -0.324964, 0.916460,
+    /// ]);
+    ///
+    /// let matrix = RotationType::Euler(euler).to_matrix();
+    /// assert!((matrix.matrix() - expected).norm() < 1e-5);
+    ///
+    /// let matrix = Rotation3::from_matrix_unchecked(expected);
+    /// assert!((RotationType::Matrix(matrix).to_euler() - euler).norm() < 1e-5);
+    /// ```
+    pub fn to_matrix(&self) -> Rotation3<f64> {
+        match self {
+            RotationType::Matrix(matrix) => *matrix,
+            _ => self.to_quaternion().to_rotation_matrix(),
+        }
+    }
+
+    /// Returns this rotation as Euler angles
+    /// following the Vicon SDK's `EulerXYZ`
+    /// convention (see [`RotationType::Euler`]).
+    ///
+    /// ```
+    /// use nalgebra::Vector3;
+    /// use vicon_sys::RotationType;
+    ///
+    /// let euler = Vector3::new(0.3, 0.2, -0.1);
+    /// let quaternion = RotationType::Euler(euler).to_quaternion();
+    ///
+    /// let round_trip = RotationType::Quaternion(quaternion).to_euler();
+    /// assert!((round_trip - euler).norm() < 1e-9);
+    /// ```
+    ///
+    /// At gimbal lock (`y = ±π/2`), X and Z rotate about
+    /// the same axis, so the whole rotation about that
+    /// axis is reported about X:
+    ///
+    /// ```
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// use nalgebra::Vector3;
+    /// use vicon_sys::RotationType;
+    ///
+    /// let up = RotationType::Euler(Vector3::new(0.3, FRAC_PI_2, 0.2));
+    /// let euler = RotationType::Matrix(up.to_matrix()).to_euler();
+    /// assert!((euler - Vector3::new(0.5, FRAC_PI_2, 0.0)).norm() < 1e-6);
+    ///
+    /// let down = RotationType::Euler(Vector3::new(0.3, -FRAC_PI_2, 0.2));
+    /// let euler = RotationType::Matrix(down.to_matrix()).to_euler();
+    /// assert!((euler - Vector3::new(0.1, -FRAC_PI_2, 0.0)).norm() < 1e-6);
+    ///
+    /// // Either way, the decomposition is the same rotation.
+    /// let matrix = RotationType::Euler(euler).to_matrix();
+    /// assert!((matrix.matrix() - down.to_matrix().matrix()).norm() < 1e-9);
+    /// ```
+    pub fn to_euler(&self) -> Vector3<f64> {
+        if let RotationType::Euler(euler) = self {
+            return *euler;
+        }
+
+        // Decompose R = Rx(x) * Ry(y) * Rz(z),
+        // for which R[(0, 2)] = sin(y).
+        let m = self.to_matrix().into_inner();
+        let sin_y = m[(0, 2)].clamp(-1.0, 1.0);
+        let y = sin_y.asin();
+
+        if sin_y.abs() < 1.0 - 1e-12 {
+            Vector3::new(
+                (-m[(1, 2)]).atan2(m[(2, 2)]),
+                y,
+                (-m[(0, 1)]).atan2(m[(0, 0)]),
+            )
+        } else {
+            // Gimbal lock: X and Z rotate about the
+            // same axis, so attribute it all to X.
+            Vector3::new(m[(2, 1)].atan2(m[(1, 1)]), y, 0.0)
+        }
+    }
+
+    /// Returns this rotation as a helical (axis-angle)
+    /// vector (see [`RotationType::Helical`]).
+    ///
+    /// ```
+    /// use nalgebra::Vector3;
+    /// use vicon_sys::RotationType;
+    ///
+    /// let euler = RotationType::Euler(Vector3::new(0.0, 0.5, 0.0));
+    /// assert!((euler.to_helical() - Vector3::new(0.0, 0.5, 0.0)).norm() < 1e-9);
+    /// ```
+    pub fn to_helical(&self) -> Vector3<f64> {
+        match self {
+            RotationType::Helical(helical) => *helical,
+            _ => self.to_quaternion().scaled_axis(),
+        }
+    }
+
+    /// Returns this rotation in the
+    /// representation requested by `output`.
+    pub fn to_output(&self, output: OutputRotation) -> RotationType {
+        match output {
+            OutputRotation::Euler => RotationType::Euler(self.to_euler()),
+            OutputRotation::Quaternion => RotationType::Quaternion(self.to_quaternion()),
+            OutputRotation::Matrix => RotationType::Matrix(self.to_matrix()),
+            OutputRotation::Helical => RotationType::Helical(self.to_helical()),
+        }
+    }
 }
 
 /// Enumeration of errors returned by a
 /// thing that [HasViconHardware].
-#[derive(Debug, Snafu)]
+#[derive(Debug, Clone, Snafu)]
 pub enum ViconError {
     /// An error from the Vicon SDK.
-    SdkError {
-        source: ViconSdkStatus,
-    },
-    OtherError {
-        message: String,
-    },
+    SdkError { source: ViconSdkStatus },
+    /// Any other error, described by its message.
+    #[snafu(display("{message}"))]
+    OtherError { message: String },
 }
+
+impl ViconError {
+    /// Returns `true` iff no read can succeed after
+    /// this error (e.g., because the thing that
+    /// [HasViconHardware] has been disconnected).
+    pub fn is_fatal(&self) -> bool {
+        matches!(
+            self,
+            ViconError::SdkError {
+                source: ViconSdkStatus::NotConnected
+                    | ViconSdkStatus::ClientConnectionFailed
+                    | ViconSdkStatus::Unimplemented
+                    | ViconSdkStatus::Unsupported
+            }
+        )
+    }
+}
+
 /// Implementation of [`TryFrom`] which
 /// returns `Ok` for _successful_
 /// [`ViconSdkStatus`] codes, and `Err`
@@ -85,7 +959,7 @@ impl TryFrom<i32> for ViconError {
 /// These status codes are derived from
 /// the codes listed in the Vicon SDK's
 /// `CTypeDefs.h` file.
-#[derive(Debug, Snafu)]
+#[derive(Debug, Clone, Snafu)]
 pub enum ViconSdkStatus {
     Unknown { code: i32 },
     Unimplemented,
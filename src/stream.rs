@@ -0,0 +1,236 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use crate::{HasViconHardware, OutputRotation, ViconError, ViconFrame};
+
+/// A stream of frames read continuously from a
+/// thing that [`HasViconHardware`] on a dedicated
+/// thread, so readers never block on the hardware.
+///
+/// The most recently read frame is available via
+/// [`ViconStream::latest`], and every frame (or error)
+/// is also queued on a bounded channel:
+///
+/// ```
+/// use vicon_sys::{
+///     HasViconHardware, OutputRotation, ViconError, ViconFrame, ViconStream,
+/// };
+///
+/// /// A Vicon which sees nothing.
+/// struct EmptyVicon(u32);
+///
+/// impl HasViconHardware for EmptyVicon {
+///     fn read_frame(&mut self, _: OutputRotation) -> Result<ViconFrame, ViconError> {
+///         self.0 += 1;
+///         Ok(ViconFrame::new(self.0, 100.0))
+///     }
+/// }
+///
+/// let stream = ViconStream::new(EmptyVicon(0), OutputRotation::Quaternion, 16);
+///
+/// // Frames are queued in the order they're read...
+/// let first = stream.frames().recv().unwrap().unwrap();
+/// let second = stream.frames().recv().unwrap().unwrap();
+/// assert_eq!(second.number, first.number + 1);
+///
+/// // ...while the latest frame is always at hand.
+/// assert!(stream.latest().unwrap().number >= second.number);
+/// ```
+///
+/// Failed reads are retried with exponential backoff,
+/// unless their error [is fatal](ViconError::is_fatal),
+/// in which case the thread stops.
+///
+/// The thread is stopped when the stream is dropped,
+/// once its current read (if any) completes; dropping
+/// the stream does not wait for the thread.
+pub struct ViconStream {
+    /// The most recently read frame, if any.
+    latest: Arc<Mutex<Option<Arc<ViconFrame>>>>,
+
+    /// The error of the most recent read, if it failed.
+    last_error: Arc<Mutex<Option<ViconError>>>,
+
+    /// All frames (or errors) read,
+    /// until the channel fills up.
+    frames: Receiver<Result<Arc<ViconFrame>, ViconError>>,
+
+    /// Flag indicating whether the
+    /// thread should keep reading.
+    running: Arc<AtomicBool>,
+
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ViconStream {
+    /// Returns a new stream continuously reading frames
+    /// with rotations of `rotation_type` from `hardware`,
+    /// queueing up to `capacity` frames at a time.
+    ///
+    /// Frames read while the queue is full are not
+    /// queued, but still become the latest frame.
+    pub fn new<H>(mut hardware: H, rotation_type: OutputRotation, capacity: usize) -> Self
+    where
+        H: HasViconHardware + Send + 'static,
+    {
+        let latest = Arc::new(Mutex::new(None));
+        let last_error = Arc::new(Mutex::new(None));
+        let (sender, frames) = mpsc::sync_channel(capacity);
+        let running = Arc::new(AtomicBool::new(true));
+
+        let thread = {
+            let latest = latest.clone();
+            let last_error = last_error.clone();
+            let running = running.clone();
+            thread::spawn(move || {
+                let mut backoff = RetryBackoff::default();
+                while running.load(Ordering::Relaxed) {
+                    let frame = hardware.read_frame(rotation_type).map(Arc::new);
+
+                    match &frame {
+                        Ok(frame) => {
+                            *latest.lock().unwrap() = Some(frame.clone());
+                            *last_error.lock().unwrap() = None;
+                        }
+                        Err(e) => *last_error.lock().unwrap() = Some(e.clone()),
+                    }
+                    let fatal = frame.as_ref().is_err_and(ViconError::is_fatal);
+                    let delay = backoff.after(&frame);
+
+                    // Sends only fail when the queue is
+                    // full or the stream was dropped.
+                    let _ = sender.try_send(frame);
+
+                    if fatal {
+                        break;
+                    }
+
+                    // Dropping the stream unparks the thread.
+                    if !delay.is_zero() {
+                        thread::park_timeout(delay);
+                    }
+                }
+            })
+        };
+
+        Self {
+            latest,
+            last_error,
+            frames,
+            running,
+            thread: Some(thread),
+        }
+    }
+
+    /// Returns the most recently read frame,
+    /// or `None` if no frame has been read.
+    pub fn latest(&self) -> Option<Arc<ViconFrame>> {
+        self.latest.lock().unwrap().clone()
+    }
+
+    /// Returns the error of the most recent read, or
+    /// `None` if it succeeded (or no read completed).
+    ///
+    /// Errors are recorded even when the queue is full,
+    /// so this shows whether [`ViconStream::latest`]
+    /// is still being updated:
+    ///
+    /// ```
+    /// use vicon_sys::{
+    ///     HasViconHardware, OutputRotation, ViconError, ViconFrame, ViconSdkStatus,
+    ///     ViconStream,
+    /// };
+    ///
+    /// /// A Vicon which has been unplugged.
+    /// struct UnpluggedVicon;
+    ///
+    /// impl HasViconHardware for UnpluggedVicon {
+    ///     fn read_frame(&mut self, _: OutputRotation) -> Result<ViconFrame, ViconError> {
+    ///         Err(ViconError::SdkError {
+    ///             source: ViconSdkStatus::NotConnected,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let stream = ViconStream::new(UnpluggedVicon, OutputRotation::Quaternion, 16);
+    ///
+    /// // The error is queued, after which the
+    /// // thread stops as no read can succeed.
+    /// assert!(stream.frames().recv().unwrap().unwrap_err().is_fatal());
+    /// assert!(stream.frames().recv().is_err());
+    /// while stream.is_running() {
+    ///     std::thread::yield_now();
+    /// }
+    ///
+    /// assert!(stream.latest().is_none());
+    /// assert!(matches!(
+    ///     stream.last_error(),
+    ///     Some(ViconError::SdkError {
+    ///         source: ViconSdkStatus::NotConnected
+    ///     })
+    /// ));
+    /// ```
+    pub fn last_error(&self) -> Option<ViconError> {
+        self.last_error.lock().unwrap().clone()
+    }
+
+    /// Returns the channel of all frames
+    /// (or errors) read by the stream.
+    pub fn frames(&self) -> &Receiver<Result<Arc<ViconFrame>, ViconError>> {
+        &self.frames
+    }
+
+    /// Returns `true` iff the stream's
+    /// thread is still reading frames.
+    pub fn is_running(&self) -> bool {
+        self.thread
+            .as_ref()
+            .is_some_and(|thread| !thread.is_finished())
+    }
+}
+
+impl Drop for ViconStream {
+    fn drop(&mut self) {
+        // Reads may block indefinitely (e.g., while
+        // reconnecting), so the thread is detached
+        // rather than joined.
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+        }
+    }
+}
+
+/// Delay before retrying the first of
+/// several consecutive failed reads.
+const MIN_RETRY_DELAY: Duration = Duration::from_millis(1);
+
+/// Maximum delay before retrying a failed read.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Exponential backoff between consecutive
+/// failed reads of a stream's hardware.
+#[derive(Debug, Default)]
+pub(crate) struct RetryBackoff {
+    delay: Duration,
+}
+
+impl RetryBackoff {
+    /// Returns the delay before the read following
+    /// one with the given `result`, which doubles
+    /// with each consecutive failed read.
+    pub(crate) fn after<T>(&mut self, result: &Result<T, ViconError>) -> Duration {
+        self.delay = match result {
+            Ok(_) => Duration::ZERO,
+            Err(_) => (self.delay * 2).clamp(MIN_RETRY_DELAY, MAX_RETRY_DELAY),
+        };
+        self.delay
+    }
+}
@@ -2,27 +2,103 @@
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 
-use std::{thread, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    thread,
+    time::{Duration, SystemTime},
+};
 
 use crate::{
-    HasViconHardware, OutputRotation, RotationType, ViconError, ViconSdkStatus, ViconSubject,
+    AxisMapping, HasViconHardware, OutputRotation, RotationType, SegmentTree, ServerOrientation,
+    StreamMode, Timecode, TimecodeStandard, ViconConfig, ViconDataTypes, ViconError, ViconFrame,
+    ViconLatency, ViconMarker, ViconMarkerFrame, ViconMulticast, ViconPointMarker, ViconSdkStatus,
+    ViconSegment, ViconSegmentModel, ViconSubject, ViconSubjectModel,
 };
-use nalgebra::{Quaternion, UnitQuaternion, Vector3};
+use nalgebra::{Matrix3, Quaternion, Rotation3, UnitQuaternion, Vector3};
 
 include!(concat!(env!("OUT_DIR"), "/libvicon.rs"));
 
-/// Maximum number of times [`ViconSystem::new`]
-/// will attempt to connect to a Vicon data stream.
-pub const MAX_CONNECT_RETRIES: usize = 3;
+mod reconnect;
+pub use reconnect::{ConnectionState, ReconnectPolicy, ReconnectingViconSystem};
+
+// Connection defaults now live in the crate's root
+// module; re-exported here for compatibility.
+pub use crate::{MAX_CONNECT_RETRIES, MAX_CONNECT_TIMEOUT};
+
+/// Invokes the segment (or marker) accessor `$read` of the Vicon
+/// SDK for the segment (or marker) named `$segment` of the subject
+/// named `$subject`, returning the `$output` struct populated by
+/// the SDK.
+///
+/// Fields of `$output` other than `Result`
+/// are initialized to the provided values.
+macro_rules! read_segment_output {
+    ($handle:expr, $subject:expr, $segment:expr, $read:ident, $output:ident { $($field:ident: $value:expr),* $(,)? }) => {{
+        let mut output = $output {
+            Result: CResult_UnknownResult as i32,
+            $($field: $value),*
+        };
+        unsafe {
+            $read($handle, $subject.as_ptr(), $segment.as_ptr(), &mut output);
+        }
+        output
+    }};
+}
 
-/// Maximim timeout [`ViconSystem::new`] will
-/// use when connecting to a vicon data stream.
-pub const MAX_CONNECT_TIMEOUT: u32 = 1000;
+/// Invokes the segment rotation accessor `$read` of the Vicon
+/// SDK via [`read_segment_output`], returning the result code,
+/// the rotation wrapped in the [`ViconRotationType`] variant
+/// `$variant` (with `$len` components), and the occlusion flag.
+macro_rules! read_segment_rotation {
+    ($handle:expr, $subject:expr, $segment:expr, $read:ident, $output:ident, $variant:ident($len:literal)) => {{
+        let output = read_segment_output!(
+            $handle,
+            $subject,
+            $segment,
+            $read,
+            $output {
+                Rotation: [0.0f64; $len],
+                Occluded: -1
+            }
+        );
+        (
+            output.Result,
+            ViconRotationType::$variant(output.Rotation),
+            output.Occluded,
+        )
+    }};
+}
 
 /// An active connection to
 /// a real Vicon data stream.
 pub struct ViconSystem {
     vicon_handle: *mut std::ffi::c_void,
+
+    /// Configuration the system was connected with,
+    /// kept up to date with later changes so the
+    /// connection can be re-established.
+    config: ViconConfig,
+
+    /// Names in the configured subject filter
+    /// not yet accepted by the Vicon.
+    pending_subject_filter: Vec<String>,
+
+    /// Static models of subjects, keyed by subject
+    /// name, cached by [`ViconSystem::subject_model`].
+    subject_models: HashMap<String, ViconSubjectModel>,
+
+    /// Kinematic trees of subjects, keyed by subject
+    /// name, cached by [`HasViconHardware::read_frame`].
+    hierarchies: HashMap<String, CachedHierarchy>,
+
+    /// Names of the frame rates reported by the Vicon,
+    /// cached by [`ViconSystem::refresh_frame_rates`].
+    frame_rate_names: Vec<std::ffi::CString>,
+
+    /// Frame rates (in Hz) reported by the Vicon,
+    /// keyed by name.
+    frame_rates: HashMap<String, f64>,
 }
 
 impl ViconSystem {
@@ -32,262 +108,1585 @@ impl ViconSystem {
     /// The provided `hostname` may optionally
     /// include a port suffix (e.g., `192.168.1.1:810`).
     pub fn new(hostname: &str) -> Result<Self, ViconError> {
-        let vicon_handle = unsafe { Client_Create() };
+        Self::connect(ViconConfig::new(hostname))
+    }
+
+    /// Returns a new system connected to a Vicon
+    /// data stream at `hostname`, streaming frames
+    /// in the given `stream_mode`.
+    pub fn with_stream_mode(hostname: &str, stream_mode: StreamMode) -> Result<Self, ViconError> {
+        Self::builder(hostname).stream_mode(stream_mode).build()
+    }
+
+    /// Returns a new system receiving a Vicon data stream
+    /// transmitted to the multicast group `multicast_group`
+    /// (e.g., `224.0.0.0:44801`) on the local adapter
+    /// with IP address `local_ip`.
+    ///
+    /// Another system connected directly to the Vicon
+    /// must be transmitting to the group; see
+    /// [`ViconSystem::start_multicast`].
+    pub fn connect_multicast(local_ip: &str, multicast_group: &str) -> Result<Self, ViconError> {
+        Self::builder(local_ip)
+            .multicast_group(multicast_group)
+            .build()
+    }
+
+    /// Returns a builder of a system connected
+    /// to a Vicon data stream at `hostname`.
+    pub fn builder(hostname: &str) -> ViconSystemBuilder {
+        ViconSystemBuilder::new(hostname)
+    }
 
-        // Try connecting to the Vicon.
-        let host_and_port = std::ffi::CString::new(hostname).unwrap();
-        let mut attempts = 0;
+    /// Returns a new system connected to a Vicon
+    /// data stream as described by `config`.
+    pub fn connect(config: ViconConfig) -> Result<Self, ViconError> {
+        let retries = config.connect_retries;
+        Self::connect_with_retries(config, retries)
+    }
+
+    /// Returns a new system connected to a Vicon data
+    /// stream as described by `config`, retrying a failed
+    /// connection up to `retries` times (regardless of
+    /// the configured `connect_retries`).
+    fn connect_with_retries(config: ViconConfig, retries: usize) -> Result<Self, ViconError> {
+        let mut system = Self {
+            vicon_handle: unsafe { Client_Create() },
+            pending_subject_filter: config.subject_filter.clone(),
+            config,
+            subject_models: HashMap::new(),
+            hierarchies: HashMap::new(),
+            frame_rate_names: Vec::new(),
+            frame_rates: HashMap::new(),
+        };
+        system.connect_and_configure(retries)?;
+
+        Ok(system)
+    }
+
+    /// Returns the configuration of this system,
+    /// including any changes made since connecting.
+    pub fn config(&self) -> &ViconConfig {
+        &self.config
+    }
+
+    /// Returns `true` iff the system is
+    /// connected to a Vicon data stream.
+    pub fn is_connected(&self) -> bool {
+        unsafe { Client_IsConnected(self.vicon_handle) != 0 }
+    }
+
+    /// Disconnects from the Vicon data stream.
+    ///
+    /// The system is also disconnected when dropped,
+    /// but any error disconnecting is then ignored.
+    pub fn disconnect(&mut self) -> Result<(), ViconError> {
+        let _: ViconError = unsafe { Client_Disconnect(self.vicon_handle) }.try_into()?;
+        Ok(())
+    }
+
+    /// Connects to the Vicon data stream described
+    /// by the system's configuration, retrying up to
+    /// `max_retries` times with backoff, and then
+    /// configures the stream.
+    fn connect_and_configure(&mut self, max_retries: usize) -> Result<(), ViconError> {
+        let host_and_port = str_to_cstring(&self.config.hostname, ViconSdkStatus::InvalidHostname)?;
+        let multicast_group = match &self.config.multicast_group {
+            Some(group) => Some(str_to_cstring(group, ViconSdkStatus::InvalidMulticastIp)?),
+            None => None,
+        };
+        let timeout = self
+            .config
+            .connect_timeout
+            .as_millis()
+            .try_into()
+            .unwrap_or(u32::MAX);
+
+        // Try connecting to the Vicon, or to
+        // the multicast group it transmits to.
+        let mut backoff = self.config.retry_backoff;
+        let mut retries = 0;
         loop {
             let status: ViconSdkStatus = unsafe {
-                Client_SetConnectionTimeout(vicon_handle, MAX_CONNECT_TIMEOUT);
-                Client_Connect(vicon_handle, host_and_port.as_ptr()).into()
+                match &multicast_group {
+                    Some(group) => Client_ConnectToMulticast(
+                        self.vicon_handle,
+                        host_and_port.as_ptr(),
+                        group.as_ptr(),
+                    ),
+                    None => {
+                        Client_SetConnectionTimeout(self.vicon_handle, timeout);
+                        Client_Connect(self.vicon_handle, host_and_port.as_ptr())
+                    }
+                }
+                .into()
             };
 
             if status.is_success() {
                 break;
             }
 
-            if attempts > MAX_CONNECT_RETRIES {
+            if retries >= max_retries {
                 return Err(ViconError::SdkError { source: status });
             }
 
-            attempts += 1;
+            thread::sleep(backoff);
+            backoff = backoff.saturating_mul(2);
+            retries += 1;
         }
 
         // Configure SDK client data stream.
-        unsafe {
-            Client_SetStreamMode(vicon_handle, CStreamMode_ClientPull.try_into().unwrap());
-            Client_SetAxisMapping(
-                vicon_handle,
-                CDirection_Forward.try_into().unwrap(),
-                CDirection_Left.try_into().unwrap(),
-                CDirection_Up.try_into().unwrap(),
-            );
+        self.set_stream_mode(self.config.stream_mode)?;
+        self.set_axis_mapping(self.config.axis_mapping)?;
+        if let Some(buffer_size) = self.config.buffer_size {
+            unsafe {
+                Client_SetBufferSize(self.vicon_handle, buffer_size);
+            }
         }
+        self.apply_data_types()?;
+        self.pending_subject_filter = self.config.subject_filter.clone();
 
-        // TODO: The reference usage by the NEST Lab
-        //       questions if these steps are needed
-        //       --copy-pasta for completeness.
-        unsafe {
-            Client_EnableSegmentData(vicon_handle);
-            Client_EnableMarkerData(vicon_handle);
+        // Resume multicast transmission; the server
+        // may still be transmitting from a previous
+        // connection.
+        if let Some(multicast) = self.config.transmit_multicast.clone() {
+            match self.start_multicast(&multicast.server_ip, &multicast.multicast_group) {
+                Err(ViconError::SdkError {
+                    source: ViconSdkStatus::ServerAlreadyTransmittingMulticast,
+                }) => {}
+                result => result?,
+            }
         }
-        thread::sleep(Duration::from_millis(1000));
 
-        Ok(Self { vicon_handle })
+        thread::sleep(self.config.settle_time);
+
+        Ok(())
+    }
+
+    /// Enables (or disables) each type of data
+    /// listed in the system's configuration.
+    fn apply_data_types(&mut self) -> Result<(), ViconError> {
+        let data_types = self.config.data_types.clone();
+
+        // Lightweight segments replace regular segments,
+        // so they're configured after them.
+        self.set_data_enabled(
+            data_types.segments,
+            Client_EnableSegmentData,
+            Client_DisableSegmentData,
+        )?;
+        self.set_data_enabled(
+            data_types.lightweight_segments,
+            Client_EnableLightweightSegmentData,
+            Client_DisableLightweightSegmentData,
+        )?;
+        self.set_data_enabled(
+            data_types.markers,
+            Client_EnableMarkerData,
+            Client_DisableMarkerData,
+        )?;
+        self.set_data_enabled(
+            data_types.unlabeled_markers,
+            Client_EnableUnlabeledMarkerData,
+            Client_DisableUnlabeledMarkerData,
+        )?;
+        self.set_data_enabled(
+            data_types.marker_rays,
+            Client_EnableMarkerRayData,
+            Client_DisableMarkerRayData,
+        )?;
+        self.set_data_enabled(
+            data_types.centroids,
+            Client_EnableCentroidData,
+            Client_DisableCentroidData,
+        )?;
+        self.set_data_enabled(
+            data_types.devices,
+            Client_EnableDeviceData,
+            Client_DisableDeviceData,
+        )?;
+        self.set_data_enabled(
+            data_types.greyscale,
+            Client_EnableGreyscaleData,
+            Client_DisableGreyscaleData,
+        )?;
+        self.set_data_enabled(
+            data_types.debug,
+            Client_EnableDebugData,
+            Client_DisableDebugData,
+        )?;
+
+        Ok(())
+    }
+
+    /// Enables (or disables) a single type of data
+    /// via the SDK's `enable` (or `disable`) function.
+    fn set_data_enabled(
+        &self,
+        enabled: bool,
+        enable: unsafe extern "C" fn(*mut CClient) -> CEnum,
+        disable: unsafe extern "C" fn(*mut CClient) -> CEnum,
+    ) -> Result<(), ViconError> {
+        let status = unsafe {
+            if enabled {
+                enable(self.vicon_handle)
+            } else {
+                disable(self.vicon_handle)
+            }
+        };
+        let _: ViconError = status.try_into()?;
+        Ok(())
+    }
+
+    /// Adds the configured subject filter to the
+    /// Vicon's data stream.
+    ///
+    /// As in the Vicon SDK's reference client, the
+    /// filter is only accepted once frames arrive, so
+    /// each name is retried on every frame until it is.
+    fn apply_subject_filter(&mut self) {
+        let vicon_handle = self.vicon_handle;
+        self.pending_subject_filter.retain(|subject_name| {
+            let status: ViconSdkStatus = std::ffi::CString::new(subject_name.as_str()).map_or(
+                ViconSdkStatus::InvalidSubjectName,
+                |subject_name| {
+                    unsafe { Client_AddToSubjectFilter(vicon_handle, subject_name.as_ptr()) }.into()
+                },
+            );
+            !status.is_success()
+        });
     }
 }
 
-impl HasViconHardware for ViconSystem {
-    fn read_frame_subjects(
+/// Builder of [`ViconSystem`]s with a custom [`ViconConfig`].
+///
+/// Options which are not set take the
+/// defaults of [`ViconConfig::default`].
+#[derive(Debug, Clone)]
+pub struct ViconSystemBuilder {
+    config: ViconConfig,
+}
+
+impl ViconSystemBuilder {
+    /// Returns a new builder of a system connected
+    /// to a Vicon data stream at `hostname`.
+    pub fn new(hostname: &str) -> Self {
+        Self::from_config(ViconConfig::new(hostname))
+    }
+
+    /// Returns a new builder starting from `config`
+    /// (e.g., as loaded from a configuration file).
+    pub fn from_config(config: ViconConfig) -> Self {
+        Self { config }
+    }
+
+    /// Sets the multicast group (e.g., `224.0.0.0:44801`)
+    /// to receive the data stream from, in which case
+    /// the builder's hostname is the IP address of the
+    /// local adapter to receive on.
+    pub fn multicast_group(mut self, multicast_group: &str) -> Self {
+        self.config.multicast_group = Some(multicast_group.to_string());
+        self
+    }
+
+    /// Sets the timeout of each connection attempt.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.config.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// Sets the number of times a failed connection
+    /// attempt is retried before giving up.
+    pub fn connect_retries(mut self, connect_retries: usize) -> Self {
+        self.config.connect_retries = connect_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry of a
+    /// failed connection attempt, which doubles
+    /// with each subsequent retry.
+    pub fn retry_backoff(mut self, retry_backoff: Duration) -> Self {
+        self.config.retry_backoff = retry_backoff;
+        self
+    }
+
+    /// Sets the delay after connecting
+    /// before the system is used.
+    pub fn settle_time(mut self, settle_time: Duration) -> Self {
+        self.config.settle_time = settle_time;
+        self
+    }
+
+    /// Sets the mode in which frames
+    /// are streamed from the Vicon.
+    pub fn stream_mode(mut self, stream_mode: StreamMode) -> Self {
+        self.config.stream_mode = stream_mode;
+        self
+    }
+
+    /// Sets the mapping of the Vicon's axes.
+    pub fn axis_mapping(mut self, axis_mapping: AxisMapping) -> Self {
+        self.config.axis_mapping = axis_mapping;
+        self
+    }
+
+    /// Sets the number of frames buffered by the client.
+    pub fn buffer_size(mut self, buffer_size: u32) -> Self {
+        self.config.buffer_size = Some(buffer_size);
+        self
+    }
+
+    /// Sets the types of data streamed from the Vicon.
+    pub fn data_types(mut self, data_types: ViconDataTypes) -> Self {
+        self.config.data_types = data_types;
+        self
+    }
+
+    /// Sets whether segments are streamed via the
+    /// lightweight protocol, which carries only
+    /// global poses.
+    pub fn lightweight_segments(mut self, lightweight_segments: bool) -> Self {
+        self.config.data_types.lightweight_segments = lightweight_segments;
+        self
+    }
+
+    /// Sets the names of the only subjects
+    /// streamed from the Vicon.
+    pub fn subject_filter<S: Into<String>>(
+        mut self,
+        subject_names: impl IntoIterator<Item = S>,
+    ) -> Self {
+        self.config.subject_filter = subject_names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the minimum quality below which subjects
+    /// are flagged as [`ViconSubject::low_quality`].
+    pub fn quality_threshold(mut self, threshold: f64) -> Self {
+        self.config.quality_threshold = Some(threshold);
+        self
+    }
+
+    /// Returns a new system connected to a
+    /// Vicon data stream as configured.
+    pub fn build(self) -> Result<ViconSystem, ViconError> {
+        ViconSystem::connect(self.config)
+    }
+}
+
+impl ViconSystem {
+    /// Asks the Vicon to transmit its data stream to
+    /// the multicast group `multicast_group` (e.g.,
+    /// `224.0.0.0:44801`) from its adapter with IP
+    /// address `server_ip`, so that any number of
+    /// systems can receive it via
+    /// [`ViconSystem::connect_multicast`].
+    pub fn start_multicast(
         &mut self,
-        rotation: OutputRotation,
-    ) -> Result<Vec<ViconSubject>, ViconError> {
-        // Get a new frame.
+        server_ip: &str,
+        multicast_group: &str,
+    ) -> Result<(), ViconError> {
+        let server_ip_c = str_to_cstring(server_ip, ViconSdkStatus::InvalidHostname)?;
+        let multicast_group_c =
+            str_to_cstring(multicast_group, ViconSdkStatus::InvalidMulticastIp)?;
+        let _: ViconError = unsafe {
+            Client_StartTransmittingMulticast(
+                self.vicon_handle,
+                server_ip_c.as_ptr(),
+                multicast_group_c.as_ptr(),
+            )
+        }
+        .try_into()?;
+
+        self.config.transmit_multicast = Some(ViconMulticast {
+            server_ip: server_ip.to_string(),
+            multicast_group: multicast_group.to_string(),
+        });
+        Ok(())
+    }
+
+    /// Asks the Vicon to stop transmitting its data stream
+    /// started by [`ViconSystem::start_multicast`].
+    pub fn stop_multicast(&mut self) -> Result<(), ViconError> {
+        let _: ViconError =
+            unsafe { Client_StopTransmittingMulticast(self.vicon_handle) }.try_into()?;
+        self.config.transmit_multicast = None;
+        Ok(())
+    }
+
+    /// Enables streaming of lightweight segments, which
+    /// require roughly a quarter of the bandwidth of
+    /// regular segments at a slight cost in accuracy.
+    ///
+    /// Lightweight segments only carry global poses,
+    /// so segments read while they are enabled
+    /// have no local pose.
+    pub fn enable_lightweight_segments(&mut self) -> Result<(), ViconError> {
+        self.config.data_types.lightweight_segments = true;
+        self.apply_data_types()
+    }
+
+    /// Disables streaming of lightweight segments,
+    /// restoring regular segments if configured.
+    pub fn disable_lightweight_segments(&mut self) -> Result<(), ViconError> {
+        self.config.data_types.lightweight_segments = false;
+        self.apply_data_types()
+    }
+
+    /// Enables streaming of unlabeled markers, which can then be
+    /// read via [`HasViconHardware::read_frame_unlabeled_markers`].
+    ///
+    /// Unlabeled markers are disabled by default,
+    /// as they can greatly increase the bandwidth
+    /// required by the data stream.
+    pub fn enable_unlabeled_markers(&mut self) -> Result<(), ViconError> {
+        self.config.data_types.unlabeled_markers = true;
+        self.apply_data_types()
+    }
+
+    /// Disables streaming of unlabeled markers.
+    pub fn disable_unlabeled_markers(&mut self) -> Result<(), ViconError> {
+        self.config.data_types.unlabeled_markers = false;
+        self.apply_data_types()
+    }
+
+    /// Enables (and disables) streaming of
+    /// each type of data from the Vicon.
+    pub fn set_data_types(&mut self, data_types: ViconDataTypes) -> Result<(), ViconError> {
+        self.config.data_types = data_types;
+        self.apply_data_types()
+    }
+
+    /// Returns the types of data currently
+    /// enabled, as reported by the Vicon SDK.
+    pub fn enabled_data_types(&self) -> ViconDataTypes {
+        let is_enabled = |is_enabled: unsafe extern "C" fn(*mut CClient) -> CBool| unsafe {
+            is_enabled(self.vicon_handle) != 0
+        };
+
+        ViconDataTypes {
+            segments: is_enabled(Client_IsSegmentDataEnabled),
+            lightweight_segments: is_enabled(Client_IsLightweightSegmentDataEnabled),
+            markers: is_enabled(Client_IsMarkerDataEnabled),
+            unlabeled_markers: is_enabled(Client_IsUnlabeledMarkerDataEnabled),
+            marker_rays: is_enabled(Client_IsMarkerRayDataEnabled),
+            centroids: is_enabled(Client_IsCentroidDataEnabled),
+            devices: is_enabled(Client_IsDeviceDataEnabled),
+            greyscale: is_enabled(Client_IsGreyscaleDataEnabled),
+            debug: is_enabled(Client_IsDebugDataEnabled),
+        }
+    }
+
+    /// Switches the mode in which frames are
+    /// streamed from the Vicon.
+    ///
+    /// Frames buffered under the previous mode
+    /// may still be returned by the next reads.
+    pub fn set_stream_mode(&mut self, stream_mode: StreamMode) -> Result<(), ViconError> {
+        let _: ViconError =
+            unsafe { Client_SetStreamMode(self.vicon_handle, stream_mode_to_sdk(stream_mode)) }
+                .try_into()?;
+        self.config.stream_mode = stream_mode;
+        Ok(())
+    }
+
+    /// Returns the mode in which frames
+    /// are streamed from the Vicon.
+    pub fn stream_mode(&self) -> StreamMode {
+        self.config.stream_mode
+    }
+
+    /// Maps the Vicon's axes onto the directions
+    /// of `axis_mapping`, which applies to all
+    /// positions and rotations read thereafter.
+    pub fn set_axis_mapping(&mut self, axis_mapping: AxisMapping) -> Result<(), ViconError> {
+        let _: ViconError = unsafe {
+            Client_SetAxisMapping(
+                self.vicon_handle,
+                axis_mapping.x().into(),
+                axis_mapping.y().into(),
+                axis_mapping.z().into(),
+            )
+        }
+        .try_into()?;
+        self.config.axis_mapping = axis_mapping;
+        Ok(())
+    }
+
+    /// Returns the current mapping of the Vicon's
+    /// axes, as reported by the Vicon SDK.
+    pub fn axis_mapping(&self) -> Result<AxisMapping, ViconError> {
+        let mut axis_mapping = COutput_GetAxisMapping {
+            XAxis: 0,
+            YAxis: 0,
+            ZAxis: 0,
+        };
+        unsafe {
+            Client_GetAxisMapping(self.vicon_handle, &mut axis_mapping);
+        }
+
+        AxisMapping::new(
+            axis_mapping.XAxis.try_into()?,
+            axis_mapping.YAxis.try_into()?,
+            axis_mapping.ZAxis.try_into()?,
+        )
+    }
+
+    /// Returns the orientation of the Vicon server
+    /// as of the most recently read frame.
+    pub fn server_orientation(&self) -> Result<ServerOrientation, ViconError> {
+        let mut orientation = COutput_GetServerOrientation {
+            Result: CResult_UnknownResult as i32,
+            Orientation: CServerOrientation_UnknownServerOrientation as i32,
+        };
+        unsafe {
+            Client_GetServerOrientation(self.vicon_handle, &mut orientation);
+        }
+        let _: ViconError = orientation.Result.try_into()?;
+
+        Ok(orientation.Orientation.into())
+    }
+
+    /// Returns all named frame rates (in Hz) reported by
+    /// the Vicon (e.g., for cameras and other devices)
+    /// as of the most recently read frame.
+    ///
+    /// These rates are refreshed on every call to
+    /// [`HasViconHardware::read_frame`], so they
+    /// track changes to the server's configuration.
+    pub fn frame_rates(&self) -> &HashMap<String, f64> {
+        &self.frame_rates
+    }
+
+    /// Returns the static model of the subject named
+    /// `subject_name`, reading it from the Vicon on first
+    /// use and caching it for all subsequent calls.
+    ///
+    /// Static models are part of the subject definition
+    /// (e.g., a VSK file) and do not change between
+    /// frames, so at least one frame must have been read
+    /// (e.g., via [`HasViconHardware::read_frame_subjects`])
+    /// before a subject's model can be read.
+    pub fn subject_model(&mut self, subject_name: &str) -> Result<&ViconSubjectModel, ViconError> {
+        if !self.subject_models.contains_key(subject_name) {
+            let model = self.read_subject_model(subject_name)?;
+            self.subject_models.insert(subject_name.to_owned(), model);
+        }
+
+        Ok(&self.subject_models[subject_name])
+    }
+
+    /// Clears all static models cached by [`ViconSystem::subject_model`]
+    /// (and the subject hierarchies cached when reading frames),
+    /// forcing them to be re-read (e.g., after subjects are re-calibrated).
+    pub fn clear_subject_models(&mut self) {
+        self.subject_models.clear();
+        self.hierarchies.clear();
+    }
+
+    /// Sets the minimum quality below which subjects
+    /// are flagged as [`ViconSubject::low_quality`],
+    /// or `None` to never flag subjects.
+    ///
+    /// Subjects whose quality is not reported
+    /// by the Vicon are never flagged.
+    pub fn set_quality_threshold(&mut self, threshold: Option<f64>) {
+        self.config.quality_threshold = threshold;
+    }
+
+    /// Returns the minimum quality below which subjects
+    /// are flagged as [`ViconSubject::low_quality`].
+    pub fn quality_threshold(&self) -> Option<f64> {
+        self.config.quality_threshold
+    }
+
+    /// Reads the static model of the subject
+    /// named `subject_name` from the current frame.
+    fn read_subject_model(&self, subject_name: &str) -> Result<ViconSubjectModel, ViconError> {
+        let subject_name = str_to_cstring(subject_name, ViconSdkStatus::InvalidSubjectName)?;
+        let segment_count = self.read_segment_count(&subject_name)?;
+
+        let mut segments = Vec::with_capacity(segment_count.try_into().unwrap());
+        for i in 0..segment_count {
+            let segment_name = self.read_segment_name(&subject_name, i)?;
+
+            let translation = read_segment_output!(
+                self.vicon_handle,
+                subject_name,
+                segment_name,
+                Client_GetSegmentStaticTranslation,
+                COutput_GetSegmentStaticTranslation {
+                    Translation: [0.0f64; 3]
+                }
+            );
+            let _: ViconError = translation.Result.try_into()?;
+
+            let rotation = read_segment_output!(
+                self.vicon_handle,
+                subject_name,
+                segment_name,
+                Client_GetSegmentStaticRotationQuaternion,
+                COutput_GetSegmentStaticRotationQuaternion {
+                    Rotation: [0.0f64; 4]
+                }
+            );
+            let _: ViconError = rotation.Result.try_into()?;
+
+            let scale = read_segment_output!(
+                self.vicon_handle,
+                subject_name,
+                segment_name,
+                Client_GetSegmentStaticScale,
+                COutput_GetSegmentStaticScale { Scale: [0.0f64; 3] }
+            );
+            let scale = not_present_as_none(
+                ViconError::try_from(scale.Result).map(|_| Vector3::from(scale.Scale)),
+            )?;
+
+            segments.push(ViconSegmentModel {
+                name: segment_name.to_str().unwrap().to_owned(),
+                // Convert from millimeters to meters.
+                translation: Vector3::from(translation.Translation) / 1000.0,
+                rotation: RotationType::Quaternion(quaternion_from_vicon(rotation.Rotation)),
+                scale,
+            });
+        }
+
+        Ok(ViconSubjectModel {
+            name: subject_name.to_str().unwrap().to_owned(),
+            segments,
+        })
+    }
+
+    /// Fetches the next available frame from the Vicon.
+    fn fetch_frame(&self) -> Result<(), ViconError> {
         let _: ViconError = unsafe { Client_GetFrame(self.vicon_handle).try_into()? };
+        Ok(())
+    }
 
-        // Count the subjects in the frame.
-        let mut subject_count = COutput_GetSubjectCount {
+    /// Refreshes the named frame rates of the current frame.
+    ///
+    /// Frame rate names are only re-read when the
+    /// number of frame rates changes, or when any
+    /// of the cached names is no longer valid.
+    fn refresh_frame_rates(&mut self) -> Result<(), ViconError> {
+        let mut rate_count = COutput_GetFrameRateCount {
             Result: CResult_UnknownResult as i32,
-            SubjectCount: 0,
+            Count: 0,
         };
         unsafe {
-            Client_GetSubjectCount(self.vicon_handle, &mut subject_count);
+            Client_GetFrameRateCount(self.vicon_handle, &mut rate_count);
         }
-        let _: ViconError = subject_count.Result.try_into()?;
-        let subject_count = subject_count.SubjectCount;
+        let _: ViconError = rate_count.Result.try_into()?;
 
-        // Visit all subjects in the frame.
-        let mut subjects = Vec::with_capacity(subject_count.try_into().unwrap());
-        for i in 0..subject_count {
-            // Get the subject's name.
-            let mut buffer = vec![0; 1024];
-            let subject_name = unsafe {
-                let _: ViconError = Client_GetSubjectName(
-                    self.vicon_handle,
-                    i,
-                    buffer.capacity() as i32,
-                    buffer.as_mut_ptr(),
-                )
-                .try_into()?;
-                buffer_to_cstring(buffer)
+        if let Some(frame_rates) = self.read_frame_rate_values()? {
+            if frame_rates.len() == rate_count.Count as usize {
+                self.frame_rates = frame_rates;
+                return Ok(());
+            }
+        }
+
+        // The server's configuration changed;
+        // re-read the names of all frame rates.
+        self.frame_rate_names.clear();
+        for i in 0..rate_count.Count {
+            self.frame_rate_names
+                .push(read_cstring(|size, buffer| unsafe {
+                    Client_GetFrameRateName(self.vicon_handle, i, size, buffer)
+                })?);
+        }
+        self.frame_rates = self.read_frame_rate_values()?.unwrap_or_default();
+
+        Ok(())
+    }
+
+    /// Reads the value of each cached frame rate name,
+    /// returning `None` if any name is no longer valid.
+    fn read_frame_rate_values(&self) -> Result<Option<HashMap<String, f64>>, ViconError> {
+        let mut frame_rates = HashMap::with_capacity(self.frame_rate_names.len());
+        for name in &self.frame_rate_names {
+            let mut rate = COutput_GetFrameRateValue {
+                Result: CResult_UnknownResult as i32,
+                Value: 0.0,
             };
+            unsafe {
+                Client_GetFrameRateValue(self.vicon_handle, name.as_ptr(), &mut rate);
+            }
+            if !ViconSdkStatus::from(rate.Result).is_success() {
+                return Ok(None);
+            }
+            frame_rates.insert(name.to_str().unwrap().to_owned(), rate.Value);
+        }
 
-            // Get the subject's segment count.
-            let mut segment_count = COutput_GetSegmentCount {
+        Ok(Some(frame_rates))
+    }
+
+    /// Reads the latency of the current frame.
+    fn read_latency(&self) -> Result<ViconLatency, ViconError> {
+        let mut total = COutput_GetLatencyTotal {
+            Result: CResult_UnknownResult as i32,
+            Total: 0.0,
+        };
+        unsafe {
+            Client_GetLatencyTotal(self.vicon_handle, &mut total);
+        }
+        let _: ViconError = total.Result.try_into()?;
+
+        let mut sample_count = COutput_GetLatencySampleCount {
+            Result: CResult_UnknownResult as i32,
+            Count: 0,
+        };
+        unsafe {
+            Client_GetLatencySampleCount(self.vicon_handle, &mut sample_count);
+        }
+        let _: ViconError = sample_count.Result.try_into()?;
+
+        let mut samples = Vec::with_capacity(sample_count.Count.try_into().unwrap());
+        for i in 0..sample_count.Count {
+            let sample_name = read_cstring(|size, buffer| unsafe {
+                Client_GetLatencySampleName(self.vicon_handle, i, size, buffer)
+            })?;
+
+            let mut sample_value = COutput_GetLatencySampleValue {
                 Result: CResult_UnknownResult as i32,
-                SegmentCount: 0,
+                Value: 0.0,
             };
             unsafe {
-                Client_GetSegmentCount(
+                Client_GetLatencySampleValue(
                     self.vicon_handle,
-                    subject_name.as_ptr(),
-                    &mut segment_count,
+                    sample_name.as_ptr(),
+                    &mut sample_value,
                 );
             }
-            let _: ViconError = segment_count.Result.try_into()?;
-            let segment_count = segment_count.SegmentCount;
+            let _: ViconError = sample_value.Result.try_into()?;
 
-            // Skip subjects with no segments.
-            if segment_count == 0 {
-                continue;
+            samples.push((
+                sample_name.to_str().unwrap().to_owned(),
+                seconds_to_duration(sample_value.Value),
+            ));
+        }
+
+        Ok(ViconLatency {
+            total: seconds_to_duration(total.Total),
+            samples,
+        })
+    }
+
+    /// Reads the fit quality of the subject
+    /// named `subject_name`.
+    fn read_quality(&self, subject_name: &std::ffi::CStr) -> Result<f64, ViconError> {
+        let mut quality = COutput_GetObjectQuality {
+            Result: CResult_UnknownResult as i32,
+            Quality: 0.0,
+        };
+        unsafe {
+            Client_GetObjectQuality(self.vicon_handle, subject_name.as_ptr(), &mut quality);
+        }
+        let _: ViconError = quality.Result.try_into()?;
+
+        Ok(quality.Quality)
+    }
+
+    /// Reads the number of segments of the
+    /// subject named `subject_name`.
+    fn read_segment_count(&self, subject_name: &std::ffi::CStr) -> Result<u32, ViconError> {
+        let mut segment_count = COutput_GetSegmentCount {
+            Result: CResult_UnknownResult as i32,
+            SegmentCount: 0,
+        };
+        unsafe {
+            Client_GetSegmentCount(self.vicon_handle, subject_name.as_ptr(), &mut segment_count);
+        }
+        let _: ViconError = segment_count.Result.try_into()?;
+        Ok(segment_count.SegmentCount)
+    }
+
+    /// Reads the name of the segment at `segment_index`
+    /// of the subject named `subject_name`.
+    fn read_segment_name(
+        &self,
+        subject_name: &std::ffi::CStr,
+        segment_index: u32,
+    ) -> Result<std::ffi::CString, ViconError> {
+        read_cstring(|size, buffer| unsafe {
+            Client_GetSegmentName(
+                self.vicon_handle,
+                subject_name.as_ptr(),
+                segment_index,
+                size,
+                buffer,
+            )
+        })
+    }
+
+    /// Reads the segment at `segment_index` of the
+    /// subject named `subject_name` from the current frame.
+    fn read_segment(
+        &self,
+        subject_name: &std::ffi::CStr,
+        segment_index: u32,
+        rotation: &OutputRotation,
+    ) -> Result<ViconSegment, ViconError> {
+        // Get the segment's name.
+        let segment_name = self.read_segment_name(subject_name, segment_index)?;
+
+        // Get the segment's global pose.
+        let (origin, translation_occluded) =
+            self.read_translation(subject_name, &segment_name, SegmentFrame::Global)?;
+        let (segment_rotation, rotation_occluded) =
+            self.read_rotation(subject_name, &segment_name, SegmentFrame::Global, rotation)?;
+
+        // Get the segment's local pose, if the
+        // stream carries local segment data;
+        // lightweight segments only carry
+        // global poses.
+        let (local_origin, local_rotation) = if self.config.data_types.lightweight_segments {
+            (None, None)
+        } else {
+            (
+                not_present_as_none(self.read_translation(
+                    subject_name,
+                    &segment_name,
+                    SegmentFrame::Local,
+                ))?,
+                not_present_as_none(self.read_rotation(
+                    subject_name,
+                    &segment_name,
+                    SegmentFrame::Local,
+                    rotation,
+                ))?,
+            )
+        };
+
+        Ok(ViconSegment {
+            name: segment_name.to_str().unwrap().to_owned(),
+            // Parents are filled in from the
+            // subject's (cached) hierarchy.
+            parent: None,
+            origin,
+            rotation: segment_rotation,
+            occluded: translation_occluded || rotation_occluded,
+            local_origin: local_origin.map(|(origin, _)| origin),
+            local_rotation: local_rotation.map(|(rotation, _)| rotation),
+        })
+    }
+
+    /// Reads the translation (in meters) of the segment
+    /// named `segment_name` in the reference `frame`,
+    /// returning the translation and a flag indicating
+    /// whether the segment is occluded.
+    fn read_translation(
+        &self,
+        subject_name: &std::ffi::CStr,
+        segment_name: &std::ffi::CStr,
+        frame: SegmentFrame,
+    ) -> Result<(Vector3<f64>, bool), ViconError> {
+        let (result, translation, occluded) = match frame {
+            SegmentFrame::Global => {
+                let output = read_segment_output!(
+                    self.vicon_handle,
+                    subject_name,
+                    segment_name,
+                    Client_GetSegmentGlobalTranslation,
+                    COutput_GetSegmentGlobalTranslation {
+                        Translation: [0.0f64; 3],
+                        Occluded: -1
+                    }
+                );
+                (output.Result, output.Translation, output.Occluded)
+            }
+            SegmentFrame::Local => {
+                let output = read_segment_output!(
+                    self.vicon_handle,
+                    subject_name,
+                    segment_name,
+                    Client_GetSegmentLocalTranslation,
+                    COutput_GetSegmentLocalTranslation {
+                        Translation: [0.0f64; 3],
+                        Occluded: -1
+                    }
+                );
+                (output.Result, output.Translation, output.Occluded)
             }
+        };
+        let _: ViconError = result.try_into()?;
 
-            // Get the _zeroth_ segment's name.
-            let mut buffer = vec![0; 1024];
-            let segment_name = unsafe {
-                let _: ViconError = Client_GetSegmentName(
+        // Convert from millimeters to meters.
+        Ok((Vector3::from(translation) / 1000.0, occluded != 0))
+    }
+
+    /// Reads the rotation of the segment named `segment_name`
+    /// in the reference `frame` and `rotation` representation,
+    /// returning the rotation and a flag indicating whether
+    /// the segment is occluded.
+    fn read_rotation(
+        &self,
+        subject_name: &std::ffi::CStr,
+        segment_name: &std::ffi::CStr,
+        frame: SegmentFrame,
+        rotation: &OutputRotation,
+    ) -> Result<(RotationType, bool), ViconError> {
+        let (result, vicon_rotation, occluded) = match (frame, rotation) {
+            (SegmentFrame::Global, OutputRotation::Euler) => read_segment_rotation!(
+                self.vicon_handle,
+                subject_name,
+                segment_name,
+                Client_GetSegmentGlobalRotationEulerXYZ,
+                COutput_GetSegmentGlobalRotationEulerXYZ,
+                Euler(3)
+            ),
+            (SegmentFrame::Global, OutputRotation::Quaternion) => read_segment_rotation!(
+                self.vicon_handle,
+                subject_name,
+                segment_name,
+                Client_GetSegmentGlobalRotationQuaternion,
+                COutput_GetSegmentGlobalRotationQuaternion,
+                Quaternion(4)
+            ),
+            (SegmentFrame::Global, OutputRotation::Matrix) => read_segment_rotation!(
+                self.vicon_handle,
+                subject_name,
+                segment_name,
+                Client_GetSegmentGlobalRotationMatrix,
+                COutput_GetSegmentGlobalRotationMatrix,
+                Matrix(9)
+            ),
+            (SegmentFrame::Global, OutputRotation::Helical) => read_segment_rotation!(
+                self.vicon_handle,
+                subject_name,
+                segment_name,
+                Client_GetSegmentGlobalRotationHelical,
+                COutput_GetSegmentGlobalRotationHelical,
+                Helical(3)
+            ),
+            (SegmentFrame::Local, OutputRotation::Euler) => read_segment_rotation!(
+                self.vicon_handle,
+                subject_name,
+                segment_name,
+                Client_GetSegmentLocalRotationEulerXYZ,
+                COutput_GetSegmentLocalRotationEulerXYZ,
+                Euler(3)
+            ),
+            (SegmentFrame::Local, OutputRotation::Quaternion) => read_segment_rotation!(
+                self.vicon_handle,
+                subject_name,
+                segment_name,
+                Client_GetSegmentLocalRotationQuaternion,
+                COutput_GetSegmentLocalRotationQuaternion,
+                Quaternion(4)
+            ),
+            (SegmentFrame::Local, OutputRotation::Matrix) => read_segment_rotation!(
+                self.vicon_handle,
+                subject_name,
+                segment_name,
+                Client_GetSegmentLocalRotationMatrix,
+                COutput_GetSegmentLocalRotationMatrix,
+                Matrix(9)
+            ),
+            (SegmentFrame::Local, OutputRotation::Helical) => read_segment_rotation!(
+                self.vicon_handle,
+                subject_name,
+                segment_name,
+                Client_GetSegmentLocalRotationHelical,
+                COutput_GetSegmentLocalRotationHelical,
+                Helical(3)
+            ),
+        };
+        let _: ViconError = result.try_into()?;
+
+        let rotation =
+            RotationType::try_from(vicon_rotation).map_err(|e| ViconError::OtherError {
+                message: e.to_string(),
+            })?;
+
+        Ok((rotation, occluded != 0))
+    }
+
+    /// Reads all labeled markers of the subject
+    /// named `subject_name` from the current frame.
+    fn read_markers(&self, subject_name: &std::ffi::CStr) -> Result<Vec<ViconMarker>, ViconError> {
+        let mut marker_count = COutput_GetMarkerCount {
+            Result: CResult_UnknownResult as i32,
+            MarkerCount: 0,
+        };
+        unsafe {
+            Client_GetMarkerCount(self.vicon_handle, subject_name.as_ptr(), &mut marker_count);
+        }
+        let _: ViconError = marker_count.Result.try_into()?;
+
+        let mut markers = Vec::with_capacity(marker_count.MarkerCount.try_into().unwrap());
+        for i in 0..marker_count.MarkerCount {
+            // Get the marker's name.
+            let marker_name = read_cstring(|size, buffer| unsafe {
+                Client_GetMarkerName(self.vicon_handle, subject_name.as_ptr(), i, size, buffer)
+            })?;
+
+            // Get the name of the marker's segment.
+            let parent_name = read_cstring(|size, buffer| unsafe {
+                Client_GetMarkerParentName(
                     self.vicon_handle,
                     subject_name.as_ptr(),
-                    0,
-                    buffer.capacity() as i32,
-                    buffer.as_mut_ptr(),
+                    marker_name.as_ptr(),
+                    size,
+                    buffer,
                 )
-                .try_into()?;
-                buffer_to_cstring(buffer)
-            };
+            })?;
+            let parent_name =
+                Some(parent_name.to_str().unwrap().to_owned()).filter(|p| !p.is_empty());
+
+            // Get the marker's translation.
+            let translation = read_segment_output!(
+                self.vicon_handle,
+                subject_name,
+                marker_name,
+                Client_GetMarkerGlobalTranslation,
+                COutput_GetMarkerGlobalTranslation {
+                    Translation: [0.0f64; 3],
+                    Occluded: -1
+                }
+            );
+            let _: ViconError = translation.Result.try_into()?;
+
+            markers.push(ViconMarker {
+                name: marker_name.to_str().unwrap().to_owned(),
+                parent: parent_name,
+                // Convert from millimeters to meters.
+                position: Vector3::from(translation.Translation) / 1000.0,
+                occluded: translation.Occluded != 0,
+            });
+        }
+
+        Ok(markers)
+    }
+
+    /// Reads the kinematic tree formed by the `segments`
+    /// of the subject named `subject_name`.
+    fn read_hierarchy(
+        &self,
+        subject_name: &std::ffi::CStr,
+        segments: &[ViconSegment],
+    ) -> Result<SegmentTree, ViconError> {
+        // Get the subject's root segment's name.
+        let root_name = read_cstring(|size, buffer| unsafe {
+            Client_GetSubjectRootSegmentName(self.vicon_handle, subject_name.as_ptr(), size, buffer)
+        })?;
 
-            // Get the segment's translation.
-            let mut segment_translation = COutput_GetSegmentGlobalTranslation {
+        // Get the names of each segment's children.
+        let mut children = Vec::with_capacity(segments.len());
+        for segment in segments {
+            let segment_name = std::ffi::CString::new(segment.name.as_str()).unwrap();
+
+            let mut child_count = COutput_GetSegmentChildCount {
                 Result: CResult_UnknownResult as i32,
-                Translation: [0.0f64; 3],
-                Occluded: -1,
+                SegmentCount: 0,
             };
             unsafe {
-                Client_GetSegmentGlobalTranslation(
+                Client_GetSegmentChildCount(
                     self.vicon_handle,
                     subject_name.as_ptr(),
                     segment_name.as_ptr(),
-                    &mut segment_translation,
+                    &mut child_count,
                 );
             }
-            let _: ViconError = segment_translation.Result.try_into()?;
-
-            // Get the segment's rotation.
-            let segment_rotation: ViconRotationType = match rotation {
-                OutputRotation::Quaternion => unsafe {
-                    let mut segment_rotation = COutput_GetSegmentGlobalRotationQuaternion {
-                        Result: CResult_UnknownResult as i32,
-                        Rotation: [0.0f64; 4],
-                        Occluded: -1,
-                    };
-                    Client_GetSegmentGlobalRotationQuaternion(
+            let _: ViconError = child_count.Result.try_into()?;
+
+            let mut child_names = Vec::with_capacity(child_count.SegmentCount.try_into().unwrap());
+            for i in 0..child_count.SegmentCount {
+                let child_name = read_cstring(|size, buffer| unsafe {
+                    Client_GetSegmentChildName(
                         self.vicon_handle,
                         subject_name.as_ptr(),
                         segment_name.as_ptr(),
-                        &mut segment_rotation,
+                        i,
+                        size,
+                        buffer,
+                    )
+                })?;
+                child_names.push(child_name.to_str().unwrap().to_owned());
+            }
+            children.push(child_names);
+        }
+
+        Ok(SegmentTree::from_children(
+            segments,
+            root_name.to_str().unwrap(),
+            &children,
+        ))
+    }
+}
+
+impl HasViconHardware for ViconSystem {
+    fn read_frame(&mut self, rotation: OutputRotation) -> Result<ViconFrame, ViconError> {
+        // Get a new frame.
+        self.fetch_frame()?;
+        let received_at = SystemTime::now();
+
+        // Add subject filter names not yet accepted by the
+        // Vicon; names still rejected are retried next frame.
+        if !self.pending_subject_filter.is_empty() {
+            self.apply_subject_filter();
+        }
+
+        // Get the frame's number.
+        let mut frame_number = COutput_GetFrameNumber {
+            Result: CResult_UnknownResult as i32,
+            FrameNumber: 0,
+        };
+        unsafe {
+            Client_GetFrameNumber(self.vicon_handle, &mut frame_number);
+        }
+        let _: ViconError = frame_number.Result.try_into()?;
+
+        // Get the frame's hardware frame number,
+        // if the server reports it.
+        let mut hardware_number = COutput_GetHardwareFrameNumber {
+            Result: CResult_UnknownResult as i32,
+            HardwareFrameNumber: 0,
+        };
+        unsafe {
+            Client_GetHardwareFrameNumber(self.vicon_handle, &mut hardware_number);
+        }
+        let hardware_number = not_present_as_none(
+            ViconError::try_from(hardware_number.Result)
+                .map(|_| hardware_number.HardwareFrameNumber),
+        )?;
+
+        // Get the system's frame rate.
+        let mut frame_rate = COutput_GetFrameRate {
+            Result: CResult_UnknownResult as i32,
+            FrameRateHz: 0.0,
+        };
+        unsafe {
+            Client_GetFrameRate(self.vicon_handle, &mut frame_rate);
+        }
+        let _: ViconError = frame_rate.Result.try_into()?;
+
+        // Get the frame's timecode, if the
+        // system has a timecode source.
+        let mut timecode = COutput_GetTimecode {
+            Result: CResult_UnknownResult as i32,
+            Hours: 0,
+            Minutes: 0,
+            Seconds: 0,
+            Frames: 0,
+            SubFrame: 0,
+            FieldFlag: 0,
+            Standard: CTimecodeStandard_None as i32,
+            SubFramesPerFrame: 0,
+            UserBits: 0,
+        };
+        unsafe {
+            Client_GetTimecode(self.vicon_handle, &mut timecode);
+        }
+        let timecode_present =
+            not_present_as_none(ViconError::try_from(timecode.Result))?.is_some();
+        let timecode = Some(Timecode {
+            hours: timecode.Hours,
+            minutes: timecode.Minutes,
+            seconds: timecode.Seconds,
+            frames: timecode.Frames,
+            sub_frame: timecode.SubFrame,
+            sub_frames_per_frame: timecode.SubFramesPerFrame,
+            field: timecode.FieldFlag != 0,
+            standard: TimecodeStandard::from(timecode.Standard),
+            user_bits: timecode.UserBits,
+        })
+        .filter(|timecode| timecode_present && timecode.standard != TimecodeStandard::None);
+
+        // Get the frame's latency, if the
+        // system reports it.
+        let latency = not_present_as_none(self.read_latency())?;
+
+        // Refresh the system's named frame rates, if
+        // the system reports them.
+        not_present_as_none(self.refresh_frame_rates())?;
+
+        // Count the subjects in the frame.
+        let mut subject_count = COutput_GetSubjectCount {
+            Result: CResult_UnknownResult as i32,
+            SubjectCount: 0,
+        };
+        unsafe {
+            Client_GetSubjectCount(self.vicon_handle, &mut subject_count);
+        }
+        let _: ViconError = subject_count.Result.try_into()?;
+        let subject_count = subject_count.SubjectCount;
+
+        // Visit all subjects in the frame.
+        let mut subjects = Vec::with_capacity(subject_count.try_into().unwrap());
+        for i in 0..subject_count {
+            // Get the subject's name.
+            let subject_name = read_cstring(|size, buffer| unsafe {
+                Client_GetSubjectName(self.vicon_handle, i, size, buffer)
+            })?;
+
+            // Skip subjects excluded by the subject filter,
+            // which the Vicon may still be sending until
+            // it accepts the filter.
+            if !self.config.subject_filter.is_empty()
+                && !self
+                    .config
+                    .subject_filter
+                    .iter()
+                    .any(|name| subject_name.to_str() == Ok(name.as_str()))
+            {
+                continue;
+            }
+
+            // Get the subject's segment count.
+            let segment_count = self.read_segment_count(&subject_name)?;
+
+            // Skip subjects with no segments.
+            if segment_count == 0 {
+                continue;
+            }
+
+            // Visit all of the subject's segments.
+            let mut segments = Vec::with_capacity(segment_count.try_into().unwrap());
+            for j in 0..segment_count {
+                segments.push(self.read_segment(&subject_name, j, &rotation)?);
+            }
+
+            // The subject's kinematic tree is part of its
+            // static model, so it's only read once (or
+            // when the subject's segments change).
+            let hierarchy = match self.hierarchies.get(subject_name.to_str().unwrap()) {
+                Some(cached) if cached.matches(&segments) => cached.tree.clone(),
+                _ => {
+                    let tree = Arc::new(self.read_hierarchy(&subject_name, &segments)?);
+                    self.hierarchies.insert(
+                        subject_name.to_str().unwrap().to_owned(),
+                        CachedHierarchy {
+                            segment_names: segments.iter().map(|s| s.name.clone()).collect(),
+                            tree: tree.clone(),
+                        },
                     );
-                    // Reorder quaternion components to match nalgebra's convention.
-                    segment_rotation.Rotation = [
-                        segment_rotation.Rotation[3],
-                        segment_rotation.Rotation[0],
-                        segment_rotation.Rotation[1],
-                        segment_rotation.Rotation[2],
-                    ];
-                    ViconRotationType::Quaternion(segment_rotation)
-                },
-                OutputRotation::Euler => {
-                    let mut segment_rotation = COutput_GetSegmentGlobalRotationEulerXYZ {
-                        Result: CResult_UnknownResult as i32,
-                        Rotation: [0.0f64; 3],
-                        Occluded: -1,
-                    };
-                    unsafe {
-                        Client_GetSegmentGlobalRotationEulerXYZ(
-                            self.vicon_handle,
-                            subject_name.as_ptr(),
-                            segment_name.as_ptr(),
-                            &mut segment_rotation,
-                        );
-                    }
-                    ViconRotationType::Euler(segment_rotation)
+                    tree
                 }
             };
+            for j in 0..segments.len() {
+                segments[j].parent = hierarchy.parent(j).map(|p| segments[p].name.clone());
+            }
 
-            let _: ViconError = segment_rotation.result().try_into()?;
+            // Get the subject's markers, if the
+            // stream carries marker data.
+            let markers =
+                not_present_as_none(self.read_markers(&subject_name))?.unwrap_or_default();
 
-            subjects.push(ViconSubject::from_vicon_frame(
+            let mut subject = ViconSubject::from_segments(
                 subject_name.to_str().unwrap().to_owned(),
-                segment_translation,
-                segment_rotation.clone(),
-            )?);
+                segments,
+                hierarchy,
+                markers,
+            );
+
+            // Get the subject's fit quality, if
+            // the server reports it, and flag
+            // poor-quality solves.
+            subject.quality = not_present_as_none(self.read_quality(&subject_name))?;
+            subject.low_quality = match (subject.quality, self.config.quality_threshold) {
+                (Some(quality), Some(threshold)) => quality < threshold,
+                _ => false,
+            };
+
+            subjects.push(subject);
+        }
+
+        Ok(ViconFrame {
+            number: frame_number.FrameNumber,
+            hardware_number,
+            frame_rate: frame_rate.FrameRateHz,
+            received_at,
+            timecode,
+            latency,
+            subjects,
+        })
+    }
+
+    fn read_frame_unlabeled_markers(&mut self) -> Result<Vec<ViconPointMarker>, ViconError> {
+        // Count the unlabeled markers in the current frame.
+        let mut marker_count = COutput_GetUnlabeledMarkerCount {
+            Result: CResult_UnknownResult as i32,
+            MarkerCount: 0,
+        };
+        unsafe {
+            Client_GetUnlabeledMarkerCount(self.vicon_handle, &mut marker_count);
+        }
+        let _: ViconError = marker_count.Result.try_into()?;
+
+        // Visit all unlabeled markers in the frame.
+        let mut markers = Vec::with_capacity(marker_count.MarkerCount.try_into().unwrap());
+        for i in 0..marker_count.MarkerCount {
+            let mut translation = COutput_GetUnlabeledMarkerGlobalTranslation {
+                Result: CResult_UnknownResult as i32,
+                Translation: [0.0f64; 3],
+                MarkerID: 0,
+            };
+            unsafe {
+                Client_GetUnlabeledMarkerGlobalTranslation(self.vicon_handle, i, &mut translation);
+            }
+            let _: ViconError = translation.Result.try_into()?;
+
+            markers.push(ViconPointMarker {
+                // Convert from millimeters to meters.
+                position: Vector3::from(translation.Translation) / 1000.0,
+                trajectory_id: translation.MarkerID,
+            });
+        }
+
+        Ok(markers)
+    }
+
+    fn read_frame_labeled_markers(&mut self) -> Result<ViconMarkerFrame, ViconError> {
+        // Get a new frame.
+        self.fetch_frame()?;
+        let received_at = SystemTime::now();
+
+        // Get the frame's number.
+        let mut frame_number = COutput_GetFrameNumber {
+            Result: CResult_UnknownResult as i32,
+            FrameNumber: 0,
+        };
+        unsafe {
+            Client_GetFrameNumber(self.vicon_handle, &mut frame_number);
+        }
+        let _: ViconError = frame_number.Result.try_into()?;
+
+        // Count the labeled markers in the frame.
+        let mut marker_count = COutput_GetLabeledMarkerCount {
+            Result: CResult_UnknownResult as i32,
+            MarkerCount: 0,
+        };
+        unsafe {
+            Client_GetLabeledMarkerCount(self.vicon_handle, &mut marker_count);
+        }
+        let _: ViconError = marker_count.Result.try_into()?;
+
+        // Visit all labeled markers in the frame.
+        let mut markers = Vec::with_capacity(marker_count.MarkerCount.try_into().unwrap());
+        for i in 0..marker_count.MarkerCount {
+            let mut translation = COutput_GetLabeledMarkerGlobalTranslation {
+                Result: CResult_UnknownResult as i32,
+                Translation: [0.0f64; 3],
+                MarkerID: 0,
+            };
+            unsafe {
+                Client_GetLabeledMarkerGlobalTranslation(self.vicon_handle, i, &mut translation);
+            }
+            let _: ViconError = translation.Result.try_into()?;
+
+            markers.push(ViconPointMarker {
+                // Convert from millimeters to meters.
+                position: Vector3::from(translation.Translation) / 1000.0,
+                trajectory_id: translation.MarkerID,
+            });
+        }
+
+        Ok(ViconMarkerFrame {
+            number: frame_number.FrameNumber,
+            received_at,
+            markers,
+        })
+    }
+
+    fn set_subject_filter(&mut self, subject_names: &[&str]) -> Result<(), ViconError> {
+        let subject_filter = subject_filter_names(subject_names)?;
+        self.clear_subject_filter()?;
+        self.config.subject_filter = subject_filter;
+        self.pending_subject_filter = self.config.subject_filter.clone();
+        self.apply_subject_filter();
+        Ok(())
+    }
+
+    fn clear_subject_filter(&mut self) -> Result<(), ViconError> {
+        let _: ViconError = unsafe { Client_ClearSubjectFilter(self.vicon_handle) }.try_into()?;
+        self.config.subject_filter.clear();
+        self.pending_subject_filter.clear();
+        Ok(())
+    }
+}
+
+impl Drop for ViconSystem {
+    fn drop(&mut self) {
+        if self.is_connected() {
+            let _ = self.disconnect();
+        }
+        unsafe {
+            Client_Destroy(self.vicon_handle);
         }
-        Ok(subjects)
     }
 }
 
 unsafe impl Send for ViconSystem {}
 
+/// A subject's kinematic tree, cached along with
+/// the names of the segments it was read for.
+struct CachedHierarchy {
+    segment_names: Vec<String>,
+    tree: Arc<SegmentTree>,
+}
+
+impl CachedHierarchy {
+    /// Returns `true` iff the tree was read for
+    /// segments with the same names as `segments`.
+    fn matches(&self, segments: &[ViconSegment]) -> bool {
+        self.segment_names
+            .iter()
+            .eq(segments.iter().map(|segment| &segment.name))
+    }
+}
+
 impl ViconSubject {
-    /// Converts raw segment data from a Vicon
-    /// to a [`ViconSubject`].
-    fn from_vicon_frame(
+    /// Builds a [`ViconSubject`] from its non-empty
+    /// list of `segments`, taking the subject's pose
+    /// from the _zeroth_ segment.
+    fn from_segments(
         name: String,
-        translation: COutput_GetSegmentGlobalTranslation,
-        vicon_rotation: ViconRotationType,
-    ) -> Result<Self, ViconError> {
-        // Calculate origins, converting from
-        // millimeters to meters.
-        let origin_x = translation.Translation[0] / 1000.0;
-        let origin_y = translation.Translation[1] / 1000.0;
-        let origin_z = translation.Translation[2] / 1000.0;
-
-        Ok(Self {
+        segments: Vec<ViconSegment>,
+        hierarchy: Arc<SegmentTree>,
+        markers: Vec<ViconMarker>,
+    ) -> Self {
+        let zeroth = &segments[0];
+        Self {
             name,
-            origin: Vector3::<f64>::new(origin_x, origin_y, origin_z),
-            rotation: RotationType::try_from(vicon_rotation.clone()).map_err(|e| {
-                ViconError::OtherError {
-                    message: e.to_string(),
-                }
-            })?,
-            occluded: vicon_rotation.occluded() || translation.Occluded != 0,
-        })
+            origin: zeroth.origin,
+            rotation: zeroth.rotation.clone(),
+            occluded: zeroth.occluded,
+            quality: None,
+            low_quality: false,
+            segments,
+            hierarchy,
+            markers,
+        }
     }
 }
 
+/// Raw rotation data read from the Vicon SDK.
 #[derive(Clone)]
 pub enum ViconRotationType {
-    Euler(COutput_GetSegmentGlobalRotationEulerXYZ),
-    Quaternion(COutput_GetSegmentGlobalRotationQuaternion),
-}
+    /// Euler angles (in radians) about the X, Y and Z axes.
+    Euler([f64; 3]),
 
-impl ViconRotationType {
-    pub fn occluded(&self) -> bool {
-        match self {
-            ViconRotationType::Euler(euler) => euler.Occluded != 0,
-            ViconRotationType::Quaternion(quaternion) => quaternion.Occluded != 0,
-        }
-    }
+    /// Quaternion components, in the
+    /// SDK's `(x, y, z, w)` order.
+    Quaternion([f64; 4]),
 
-    pub fn result(&self) -> i32 {
-        match self {
-            ViconRotationType::Euler(euler) => euler.Result,
-            ViconRotationType::Quaternion(quaternion) => quaternion.Result,
-        }
-    }
+    /// Rotation matrix components, in row-major order.
+    Matrix([f64; 9]),
+
+    /// Helical (axis-angle) vector, whose direction is
+    /// the axis of rotation and whose magnitude is the
+    /// angle of rotation in radians.
+    Helical([f64; 3]),
 }
 
 impl TryFrom<ViconRotationType> for RotationType {
     type Error = String;
     fn try_from(value: ViconRotationType) -> Result<Self, Self::Error> {
         match value {
-            ViconRotationType::Euler(euler) => {
-                let euler = Vector3::new(euler.Rotation[0], euler.Rotation[1], euler.Rotation[2]);
-                return Ok(Self::Euler(euler));
-            }
+            ViconRotationType::Euler(euler) => Ok(Self::Euler(Vector3::from(euler))),
             ViconRotationType::Quaternion(quaternion) => {
-                let quaternion = UnitQuaternion::from_quaternion(Quaternion::new(
-                    quaternion.Rotation[0],
-                    quaternion.Rotation[1],
-                    quaternion.Rotation[2],
-                    quaternion.Rotation[3],
-                ));
-                return Ok(Self::Quaternion(quaternion));
+                Ok(Self::Quaternion(quaternion_from_vicon(quaternion)))
             }
+            ViconRotationType::Matrix(matrix) => Ok(Self::Matrix(
+                Rotation3::from_matrix_unchecked(Matrix3::from_row_slice(&matrix)),
+            )),
+            ViconRotationType::Helical(helical) => Ok(Self::Helical(Vector3::from(helical))),
         }
     }
 }
 
+/// Utility which converts quaternion components
+/// in the Vicon SDK's `(x, y, z, w)` order
+/// to a [`UnitQuaternion`].
+fn quaternion_from_vicon(quaternion: [f64; 4]) -> UnitQuaternion<f64> {
+    // Reorder quaternion components to match nalgebra's convention.
+    UnitQuaternion::from_quaternion(Quaternion::new(
+        quaternion[3],
+        quaternion[0],
+        quaternion[1],
+        quaternion[2],
+    ))
+}
+
+/// Utility which converts a (non-negative) number
+/// of `seconds` reported by the Vicon SDK to a
+/// [`Duration`], clamping invalid values to zero.
+fn seconds_to_duration(seconds: f64) -> Duration {
+    Duration::try_from_secs_f64(seconds).unwrap_or_default()
+}
+
+/// Reference frames in which the
+/// Vicon SDK reports segment poses.
+#[derive(Clone, Copy)]
+enum SegmentFrame {
+    /// Relative to the origin of
+    /// the motion capture volume.
+    Global,
+
+    /// Relative to the segment's parent segment.
+    Local,
+}
+
+/// Converts `value` to a C string, returning
+/// `invalid` if it contains a nul byte.
+fn str_to_cstring(value: &str, invalid: ViconSdkStatus) -> Result<std::ffi::CString, ViconError> {
+    std::ffi::CString::new(value).map_err(|_| ViconError::SdkError { source: invalid })
+}
+
+/// Returns `subject_names` as an owned subject filter,
+/// or [`ViconSdkStatus::InvalidSubjectName`] if any
+/// name can't be passed to the Vicon SDK.
+fn subject_filter_names(subject_names: &[&str]) -> Result<Vec<String>, ViconError> {
+    subject_names
+        .iter()
+        .map(|name| {
+            str_to_cstring(name, ViconSdkStatus::InvalidSubjectName)?;
+            Ok(name.to_string())
+        })
+        .collect()
+}
+
+/// Converts `stream_mode` to its
+/// Vicon SDK `CStreamMode` equivalent.
+fn stream_mode_to_sdk(stream_mode: StreamMode) -> CEnum {
+    let mode = match stream_mode {
+        StreamMode::ClientPull => CStreamMode_ClientPull,
+        StreamMode::ClientPullPreFetch => CStreamMode_ClientPullPreFetch,
+        StreamMode::ServerPush => CStreamMode_ServerPush,
+    };
+    mode.try_into().unwrap()
+}
+
+/// Utility which maps a [`ViconSdkStatus::NotPresent`]
+/// error to `None`, for data which may be absent
+/// from the Vicon data stream.
+fn not_present_as_none<T>(result: Result<T, ViconError>) -> Result<Option<T>, ViconError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(ViconError::SdkError {
+            source: ViconSdkStatus::NotPresent,
+        }) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Size of the buffers used to read
+/// names (subjects, segments, etc.)
+/// from the Vicon SDK.
+const NAME_BUFFER_SIZE: usize = 1024;
+
+/// Utility which reads a name from the Vicon
+/// SDK by invoking `read` with the size of, and
+/// a pointer to, a freshly allocated buffer.
+fn read_cstring(
+    read: impl FnOnce(i32, *mut std::os::raw::c_char) -> CEnum,
+) -> Result<std::ffi::CString, ViconError> {
+    let mut buffer = vec![0; NAME_BUFFER_SIZE];
+    let _: ViconError = read(buffer.capacity() as i32, buffer.as_mut_ptr()).try_into()?;
+    Ok(unsafe { buffer_to_cstring(buffer) })
+}
+
 /// Utility which converts a `buffer`
 /// of raw C characters into a valid
 /// C string, stripping all `0`s from
@@ -0,0 +1,260 @@
+use std::{thread, time::Duration};
+
+use crate::{
+    HasViconHardware, OutputRotation, ViconConfig, ViconError, ViconFrame, ViconMarkerFrame,
+    ViconPointMarker, ViconSdkStatus,
+};
+
+use super::ViconSystem;
+
+/// States of the connection of a
+/// [`ReconnectingViconSystem`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// Not connected to a Vicon data stream.
+    Disconnected,
+
+    /// Attempting to connect to a Vicon data stream,
+    /// counting attempts since the last connection.
+    Connecting { attempt: usize },
+
+    /// Connected to a Vicon data stream.
+    Connected,
+}
+
+/// Policy governing how a [`ReconnectingViconSystem`]
+/// backs off between connection attempts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconnectPolicy {
+    /// Delay after the first failed attempt.
+    pub initial_delay: Duration,
+
+    /// Maximum delay between attempts.
+    pub max_delay: Duration,
+
+    /// Factor by which the delay grows
+    /// after each failed attempt.
+    pub multiplier: f64,
+
+    /// Number of consecutive failed attempts after
+    /// which reads fail, or `None` to retry forever.
+    pub max_attempts: Option<usize>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2.0,
+            max_attempts: None,
+        }
+    }
+}
+
+/// A [`ViconSystem`] which transparently reconnects
+/// to its Vicon data stream (e.g., after the Vicon
+/// server restarts), re-applying its configuration.
+///
+/// Reads block while reconnecting, backing off
+/// between attempts per the [`ReconnectPolicy`]
+/// (which replaces the `connect_retries` and
+/// `retry_backoff` of the [`ViconConfig`]).
+pub struct ReconnectingViconSystem {
+    /// Configuration to (re)connect with, including
+    /// any changes made to the connected system.
+    config: ViconConfig,
+
+    policy: ReconnectPolicy,
+
+    /// The connected system, if any.
+    system: Option<ViconSystem>,
+
+    state: ConnectionState,
+
+    /// Callback invoked on every state change.
+    on_state_change: Option<Box<dyn FnMut(ConnectionState) + Send>>,
+}
+
+impl ReconnectingViconSystem {
+    /// Returns a new system which connects to the
+    /// Vicon data stream described by `config` on
+    /// its first read, and reconnects per `policy`.
+    pub fn new(config: ViconConfig, policy: ReconnectPolicy) -> Self {
+        Self {
+            config,
+            policy,
+            system: None,
+            state: ConnectionState::Disconnected,
+            on_state_change: None,
+        }
+    }
+
+    /// Sets a callback invoked with the system's new
+    /// state whenever its connection state changes.
+    ///
+    /// To receive states on another thread, the
+    /// callback can send them over a channel:
+    ///
+    /// ```no_run
+    /// use std::sync::mpsc;
+    /// use vicon_sys::sys::{ReconnectPolicy, ReconnectingViconSystem};
+    /// use vicon_sys::ViconConfig;
+    ///
+    /// let (sender, receiver) = mpsc::channel();
+    /// let vicon = ReconnectingViconSystem::new(
+    ///     ViconConfig::new("localhost"),
+    ///     ReconnectPolicy::default(),
+    /// )
+    /// .on_state_change(move |state| {
+    ///     let _ = sender.send(state);
+    /// });
+    /// ```
+    pub fn on_state_change(
+        mut self,
+        on_state_change: impl FnMut(ConnectionState) + Send + 'static,
+    ) -> Self {
+        self.on_state_change = Some(Box::new(on_state_change));
+        self
+    }
+
+    /// Returns the system's connection state.
+    pub fn state(&self) -> ConnectionState {
+        self.state
+    }
+
+    /// Returns the configuration the
+    /// system (re)connects with.
+    pub fn config(&self) -> &ViconConfig {
+        self.system
+            .as_ref()
+            .map_or(&self.config, |system| system.config())
+    }
+
+    /// Returns the connected system, if any.
+    ///
+    /// Configuration changes made to the returned
+    /// system are re-applied after reconnecting.
+    pub fn system(&mut self) -> Option<&mut ViconSystem> {
+        self.system.as_mut()
+    }
+
+    /// Transitions to `state`, notifying
+    /// the state change callback (if any).
+    fn set_state(&mut self, state: ConnectionState) {
+        if self.state == state {
+            return;
+        }
+
+        self.state = state;
+        if let Some(on_state_change) = &mut self.on_state_change {
+            on_state_change(state);
+        }
+    }
+
+    /// Connects to the Vicon data stream if not
+    /// already connected, backing off between
+    /// attempts, and returns the connected system.
+    fn connect(&mut self) -> Result<&mut ViconSystem, ViconError> {
+        if self.system.is_none() {
+            let mut delay = self.policy.initial_delay;
+            let mut attempt = 0;
+            let system = loop {
+                attempt += 1;
+                self.set_state(ConnectionState::Connecting { attempt });
+
+                // The policy governs all retries, so each
+                // attempt connects only once.
+                match ViconSystem::connect_with_retries(self.config.clone(), 0) {
+                    Ok(system) => break system,
+                    Err(e) => {
+                        if self.policy.max_attempts.is_some_and(|max| attempt >= max) {
+                            self.set_state(ConnectionState::Disconnected);
+                            return Err(e);
+                        }
+                    }
+                }
+
+                thread::sleep(delay);
+                delay = Duration::try_from_secs_f64(delay.as_secs_f64() * self.policy.multiplier)
+                    .unwrap_or(self.policy.max_delay)
+                    .min(self.policy.max_delay);
+            };
+
+            self.system = Some(system);
+            self.set_state(ConnectionState::Connected);
+        }
+
+        Ok(self.system.as_mut().unwrap())
+    }
+
+    /// Drops the connected system, keeping its
+    /// configuration to reconnect with.
+    fn disconnected(&mut self) {
+        if let Some(system) = self.system.take() {
+            self.config = system.config().clone();
+        }
+        self.set_state(ConnectionState::Disconnected);
+    }
+
+    /// Invokes `operation` on the connected system,
+    /// reconnecting and retrying if the system
+    /// has been disconnected.
+    fn with_system<T>(
+        &mut self,
+        mut operation: impl FnMut(&mut ViconSystem) -> Result<T, ViconError>,
+    ) -> Result<T, ViconError> {
+        loop {
+            let system = self.connect()?;
+            match operation(system) {
+                Err(e) if is_disconnection(&e) || !system.is_connected() => self.disconnected(),
+                result => return result,
+            }
+        }
+    }
+}
+
+impl HasViconHardware for ReconnectingViconSystem {
+    fn read_frame(&mut self, rotation_type: OutputRotation) -> Result<ViconFrame, ViconError> {
+        self.with_system(|system| system.read_frame(rotation_type))
+    }
+
+    fn read_frame_unlabeled_markers(&mut self) -> Result<Vec<ViconPointMarker>, ViconError> {
+        self.with_system(|system| system.read_frame_unlabeled_markers())
+    }
+
+    fn read_frame_labeled_markers(&mut self) -> Result<ViconMarkerFrame, ViconError> {
+        self.with_system(|system| system.read_frame_labeled_markers())
+    }
+
+    fn set_subject_filter(&mut self, subject_names: &[&str]) -> Result<(), ViconError> {
+        match &mut self.system {
+            Some(system) => system.set_subject_filter(subject_names),
+            None => {
+                self.config.subject_filter = super::subject_filter_names(subject_names)?;
+                Ok(())
+            }
+        }
+    }
+
+    fn clear_subject_filter(&mut self) -> Result<(), ViconError> {
+        match &mut self.system {
+            Some(system) => system.clear_subject_filter(),
+            None => {
+                self.config.subject_filter.clear();
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Returns `true` iff `error` indicates the
+/// connection to the Vicon has been lost.
+fn is_disconnection(error: &ViconError) -> bool {
+    matches!(
+        error,
+        ViconError::SdkError {
+            source: ViconSdkStatus::NotConnected | ViconSdkStatus::ClientConnectionFailed
+        }
+    )
+}
@@ -0,0 +1,295 @@
+use std::{fmt, str::FromStr};
+
+use crate::ViconError;
+
+/// Enumeration of timecode standards
+/// supported by the Vicon SDK.
+///
+/// These standards are derived from the
+/// `CTimecodeStandard` enum listed in the
+/// Vicon SDK's `CTypeDefs.h` file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimecodeStandard {
+    /// No timecode source is configured.
+    None,
+
+    /// 25 frames per second.
+    Pal,
+
+    /// 30 frames per second (non-drop).
+    Ntsc,
+
+    /// 29.97 frames per second (drop-frame).
+    NtscDrop,
+
+    /// 24 frames per second.
+    Film,
+
+    /// 23.976 frames per second.
+    NtscFilm,
+
+    /// 30 frames per second.
+    Atsc,
+}
+
+impl TimecodeStandard {
+    /// Returns the number of frames labeled within each
+    /// second of a timecode following this standard,
+    /// or `None` if there is no timecode standard.
+    pub fn frames_per_second(&self) -> Option<u32> {
+        match self {
+            TimecodeStandard::None => None,
+            TimecodeStandard::Pal => Some(25),
+            TimecodeStandard::Ntsc | TimecodeStandard::NtscDrop | TimecodeStandard::Atsc => {
+                Some(30)
+            }
+            TimecodeStandard::Film | TimecodeStandard::NtscFilm => Some(24),
+        }
+    }
+
+    /// Returns `true` iff this standard
+    /// uses drop-frame timecodes.
+    pub fn is_drop_frame(&self) -> bool {
+        matches!(self, TimecodeStandard::NtscDrop)
+    }
+}
+
+impl From<i32> for TimecodeStandard {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => Self::Pal,
+            2 => Self::Ntsc,
+            3 => Self::NtscDrop,
+            4 => Self::Film,
+            5 => Self::NtscFilm,
+            6 => Self::Atsc,
+            _ => Self::None,
+        }
+    }
+}
+
+/// A SMPTE timecode, as reported by the
+/// Vicon SDK for a single frame.
+///
+/// Timecodes are formatted as (and can be
+/// parsed from) `HH:MM:SS:FF` strings, or
+/// `HH:MM:SS;FF` strings if drop-frame:
+///
+/// ```
+/// use vicon_sys::{Timecode, TimecodeStandard};
+///
+/// let mut timecode: Timecode = "01:02:03:04".parse().unwrap();
+/// assert_eq!(timecode.hours, 1);
+/// assert_eq!(timecode.frames, 4);
+/// assert_eq!(timecode.to_string(), "01:02:03:04");
+///
+/// // Frame counts depend on the timecode's standard.
+/// assert_eq!(timecode.to_frame_count(), None);
+/// timecode.standard = TimecodeStandard::Pal;
+/// assert_eq!(timecode.to_frame_count(), Some(((3600 + 2 * 60 + 3) * 25) + 4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timecode {
+    /// The timecode's hours.
+    pub hours: u32,
+
+    /// The timecode's minutes.
+    pub minutes: u32,
+
+    /// The timecode's seconds.
+    pub seconds: u32,
+
+    /// The timecode's frames.
+    pub frames: u32,
+
+    /// The Vicon frame within the timecode frame,
+    /// when the Vicon captures at a higher rate
+    /// than the timecode.
+    pub sub_frame: u32,
+
+    /// The number of Vicon frames
+    /// within each timecode frame.
+    pub sub_frames_per_frame: u32,
+
+    /// Flag indicating whether the frame is
+    /// the second field of an interlaced frame.
+    pub field: bool,
+
+    /// The timecode's standard.
+    pub standard: TimecodeStandard,
+
+    /// The timecode's user bits.
+    pub user_bits: u32,
+}
+
+impl Timecode {
+    /// Returns a new timecode at `hours:minutes:seconds:frames`
+    /// following `standard`, with no sub-frames or user bits.
+    pub fn new(
+        hours: u32,
+        minutes: u32,
+        seconds: u32,
+        frames: u32,
+        standard: TimecodeStandard,
+    ) -> Self {
+        Self {
+            hours,
+            minutes,
+            seconds,
+            frames,
+            sub_frame: 0,
+            sub_frames_per_frame: 1,
+            field: false,
+            standard,
+            user_bits: 0,
+        }
+    }
+
+    /// Returns `true` iff the timecode labels a frame
+    /// which exists in its standard: its minutes and
+    /// seconds are below 60, its frames are below the
+    /// standard's frames per second (if any), and it
+    /// is not a label skipped by drop-frame timecodes.
+    ///
+    /// ```
+    /// use vicon_sys::{Timecode, TimecodeStandard};
+    ///
+    /// assert!(Timecode::new(0, 0, 59, 24, TimecodeStandard::Pal).is_valid());
+    /// assert!(!Timecode::new(0, 0, 59, 25, TimecodeStandard::Pal).is_valid());
+    ///
+    /// // Drop-frame timecodes skip frames 00 and 01
+    /// // of every minute except every tenth minute.
+    /// assert!(!Timecode::new(0, 1, 0, 0, TimecodeStandard::NtscDrop).is_valid());
+    /// assert!(Timecode::new(0, 1, 0, 2, TimecodeStandard::NtscDrop).is_valid());
+    /// assert!(Timecode::new(0, 10, 0, 0, TimecodeStandard::NtscDrop).is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        if self.minutes >= 60 || self.seconds >= 60 {
+            return false;
+        }
+
+        if let Some(frames_per_second) = self.standard.frames_per_second() {
+            if self.frames >= frames_per_second {
+                return false;
+            }
+        }
+
+        // Labels are only dropped in minutes
+        // which are not a multiple of ten.
+        let minute_of_ten = self.minutes % 10;
+        let dropped = self.standard.is_drop_frame()
+            && self.seconds == 0
+            && self.frames < 2
+            && minute_of_ten != 0;
+        !dropped
+    }
+
+    /// Returns the number of timecode frames elapsed
+    /// since `00:00:00:00`, or `None` if the timecode
+    /// has no [`TimecodeStandard`].
+    ///
+    /// Drop-frame timecodes skip frame labels `00` and
+    /// `01` at the start of every minute except every
+    /// tenth minute, which is accounted for here:
+    ///
+    /// ```
+    /// use vicon_sys::{Timecode, TimecodeStandard};
+    ///
+    /// // 00:00:59:29 is immediately followed by 00:01:00:02.
+    /// let before = Timecode::new(0, 0, 59, 29, TimecodeStandard::NtscDrop);
+    /// let after = Timecode::new(0, 1, 0, 2, TimecodeStandard::NtscDrop);
+    /// assert_eq!(before.to_frame_count(), Some(1799));
+    /// assert_eq!(after.to_frame_count(), Some(1800));
+    ///
+    /// // One hour of drop-frame timecode holds 107892 frames.
+    /// let hour = Timecode::new(1, 0, 0, 0, TimecodeStandard::NtscDrop);
+    /// assert_eq!(hour.to_frame_count(), Some(107892));
+    /// ```
+    pub fn to_frame_count(&self) -> Option<u64> {
+        let frames_per_second = u64::from(self.standard.frames_per_second()?);
+        let total_minutes = 60 * u64::from(self.hours) + u64::from(self.minutes);
+        let total_seconds = 60 * total_minutes + u64::from(self.seconds);
+        let mut count = frames_per_second * total_seconds + u64::from(self.frames);
+
+        if self.standard.is_drop_frame() {
+            count -= 2 * (total_minutes - total_minutes / 10);
+        }
+
+        Some(count)
+    }
+}
+
+/// Formats a timecode as an `HH:MM:SS:FF` string, or as
+/// `HH:MM:SS;FF` for drop-frame timecodes, so that it
+/// parses back to the same standard:
+///
+/// ```
+/// use vicon_sys::{Timecode, TimecodeStandard};
+///
+/// let timecode = Timecode::new(0, 1, 0, 2, TimecodeStandard::NtscDrop);
+/// assert_eq!(timecode.to_string(), "00:01:00;02");
+///
+/// let parsed: Timecode = timecode.to_string().parse().unwrap();
+/// assert_eq!(parsed, timecode);
+/// ```
+impl fmt::Display for Timecode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = if self.standard.is_drop_frame() {
+            ';'
+        } else {
+            ':'
+        };
+
+        write!(
+            f,
+            "{:02}:{:02}:{:02}{}{:02}",
+            self.hours, self.minutes, self.seconds, separator, self.frames
+        )
+    }
+}
+
+/// Parses a timecode from an `HH:MM:SS:FF` string; the
+/// conventional drop-frame form `HH:MM:SS;FF` is also
+/// accepted, and yields a [`TimecodeStandard::NtscDrop`]
+/// timecode. All other timecodes have no standard.
+///
+/// Timecodes which are not [valid](Timecode::is_valid)
+/// are rejected:
+///
+/// ```
+/// use vicon_sys::Timecode;
+///
+/// assert!("00:00:59;29".parse::<Timecode>().is_ok());
+/// assert!("00:01:00;00".parse::<Timecode>().is_err());
+/// assert!("00:00:00;30".parse::<Timecode>().is_err());
+/// assert!("00:60:00:00".parse::<Timecode>().is_err());
+/// ```
+impl FromStr for Timecode {
+    type Err = ViconError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ViconError::OtherError {
+            message: format!("invalid timecode: {s:?}"),
+        };
+
+        let standard = if s.contains(';') {
+            TimecodeStandard::NtscDrop
+        } else {
+            TimecodeStandard::None
+        };
+
+        let fields = s
+            .split([':', ';'])
+            .map(|field| field.parse::<u32>().map_err(|_| invalid()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match fields[..] {
+            [hours, minutes, seconds, frames] => {
+                Some(Self::new(hours, minutes, seconds, frames, standard))
+                    .filter(Timecode::is_valid)
+                    .ok_or_else(invalid)
+            }
+            _ => Err(invalid()),
+        }
+    }
+}